@@ -18,14 +18,14 @@
 
 use std::io::stdin;
 
-use failure::{Error, ResultExt};
-use revolut_customer::Client;
+use anyhow::{bail, Context, Error};
+use revolut_customer::{private::ConfirmResult, Client};
 
 fn main() {
     if let Err(ref e) = run() {
         println!("error: {}", e);
 
-        for e in e.iter_causes() {
+        for e in e.chain().skip(1) {
             println!("caused by: {}", e);
         }
 
@@ -59,16 +59,35 @@ fn run() -> Result<(), Error> {
     println!();
 
     println!("Log in successful, you should receive an SMS with the code");
-    println!("Code: ");
     let mut code = String::new();
-    let _ = stdin()
-        .read_line(&mut code)
-        .context("unable to read the code")?;
-    println!();
+    while code.trim().is_empty() {
+        println!("Code (leave it empty to receive it again): ");
+        code.clear();
+        let read = stdin()
+            .read_line(&mut code)
+            .context("unable to read the code")?;
+        println!();
+        if read == 0 {
+            bail!("the input ended before the code was provided");
+        }
 
-    let _ = client
+        if code.trim().is_empty() {
+            client
+                .resend_sign_in_code(phone.trim())
+                .context("error sending the code again")?;
+        }
+    }
+
+    let result = client
         .confirm_sign_in(phone.trim(), code.trim())
         .context("error confirming the login")?;
+    if let ConfirmResult::VerificationRequired(challenge) = result {
+        println!(
+            "Additional verification required ({}), please complete it in the app",
+            challenge.kind()
+        );
+        return Ok(());
+    }
 
     println!("User ID: {}", client.user_id().unwrap());
     println!("Access token: {}", client.access_token().unwrap());
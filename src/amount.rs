@@ -1,6 +1,7 @@
 //! Revolut currency amount
 //!
-//! This module holds the `Amount` type and the `ParseError`.
+//! This module holds the `Amount` type, the `SignedAmount` type with its `Direction`, the
+//! `RoundingMode`, the `ParseError` and the `ConversionError`.
 //!
 //! The maximum and minimum amount values can in any case be known by using `max_value()` and
 //! `min_value()` functions in the `Amount` type, or the `MAX` and `MIN` constants:
@@ -17,14 +18,16 @@
 //! ```
 
 use std::{
+    convert::TryFrom,
     fmt,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
     str::FromStr,
     u64,
 };
 
-use failure::{Error, Fail, ResultExt};
-use serde::{Deserialize, Serialize};
+use anyhow::{Context, Error};
+use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Largest possible currency amount.
 pub const MAX: Amount = Amount::max_value();
@@ -34,7 +37,8 @@ pub const MIN: Amount = Amount::min_value();
 /// Revolut currency amount
 ///
 /// This data structure can be used the same way as any other number. An `Amount` can be added or
-/// subtracted to another `Amount`, and it can be divided and multiplied by an integer. All
+/// subtracted to another `Amount` or to an integer, and it can be divided and multiplied by an
+/// integer. All
 /// operations that are defined in the `Amount` scope and that are exact can be used directly as
 /// usual integer / float point operations.
 ///
@@ -57,7 +61,10 @@ pub const MIN: Amount = Amount::min_value();
 /// assert_eq!(add_ten, Amount::from_repr(11_65)); // 11.65
 /// ```
 ///
-/// They can be divided and multiplied by any other unsigned integer:
+/// They can be divided and multiplied by any other unsigned integer. The remainder operator
+/// computes the modulo in whole currency units, not in cents, so `amount % 1` gives the cents of
+/// the amount. It panics if the divisor is zero, and
+/// [`checked_rem()`](#method.checked_rem) can be used to avoid it:
 ///
 /// ```
 /// # use revolut_customer::Amount;
@@ -72,6 +79,24 @@ pub const MIN: Amount = Amount::min_value();
 /// assert_eq!(amount, Amount::from_repr(0_33)); // 0.33
 /// ```
 ///
+/// Unsigned integers can also be added to and subtracted from an amount. As with the remainder,
+/// the integer is interpreted in whole currency units, not as an internal representation, so
+/// `amount + 5u64` adds 5 to the amount. The
+/// [`checked_add_units()`](#method.checked_add_units) and
+/// [`checked_sub_units()`](#method.checked_sub_units) methods avoid panicking on overflow:
+///
+/// ```
+/// # use revolut_customer::Amount;
+/// let mut amount = Amount::from_repr(1_65); // 1.65
+/// amount += 5u64;
+/// assert_eq!(amount, Amount::from_repr(6_65)); // 6.65
+///
+/// amount = amount - 2u8;
+/// assert_eq!(amount, Amount::from_repr(4_65)); // 4.65
+///
+/// assert_eq!(amount + Amount::from_repr(5), Amount::from_repr(4_70)); // 4.70
+/// ```
+///
 /// Amounts can easily be displayed using the `Display` trait as any other number:
 ///
 /// ```
@@ -87,7 +112,7 @@ pub const MIN: Amount = Amount::min_value();
 /// let amount = Amount::from_repr(0_56); // 0.56
 /// assert_eq!(format!("{:.1}", amount), "0.6");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct Amount {
     value: u64,
@@ -104,6 +129,385 @@ impl Amount {
         self.value
     }
 
+    /// Creates a new amount from its major units and its minor units (cents).
+    ///
+    /// The minor units must be between 0 and 99:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let amount = Amount::from_major_minor(1, 65).unwrap();
+    /// assert_eq!(amount, Amount::from_repr(1_65));
+    ///
+    /// assert!(Amount::from_major_minor(1, 100).is_err());
+    /// ```
+    pub fn from_major_minor(major: u64, minor: u8) -> Result<Self, Error> {
+        if minor >= 100 {
+            return Err(ConversionError::InvalidMinor { minor }.into());
+        }
+
+        major
+            .checked_mul(1_00)
+            .and_then(|value| value.checked_add(u64::from(minor)))
+            .map(Self::from_repr)
+            .ok_or_else(|| ConversionError::Overflow.into())
+    }
+
+    /// Splits the amount in its major units and its minor units (cents).
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// assert_eq!(Amount::from_repr(11_65).split(), (11, 65));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn split(self) -> (u64, u8) {
+        (self.value / 1_00, (self.value % 1_00) as u8)
+    }
+
+    /// Formats the amount with two decimal digits, grouping the units in thousands.
+    ///
+    /// The `Display` implementation does not group the units, which makes big amounts hard to
+    /// read:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let amount = Amount::from_repr(12_345_67);
+    /// assert_eq!(amount.format_grouped(',', '.'), "12,345.67");
+    /// assert_eq!(amount.format_grouped('.', ','), "12.345,67");
+    /// assert_eq!(Amount::from_repr(5_00).format_grouped(',', '.'), "5.00");
+    /// ```
+    pub fn format_grouped(self, thousands_separator: char, decimal_separator: char) -> String {
+        let (units, cents) = self.split();
+        let digits = units.to_string();
+
+        let mut result = String::with_capacity(digits.len() * 4 / 3 + 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                result.push(thousands_separator);
+            }
+            result.push(digit);
+        }
+        result.push(decimal_separator);
+        result.push(char::from(b'0' + cents / 10));
+        result.push(char::from(b'0' + cents % 10));
+        result
+    }
+
+    /// Parses an amount written for a given locale.
+    ///
+    /// The given decimal separator is used instead of the dot, and if a currency symbol is
+    /// provided, it's removed from the start or the end of the amount, together with any
+    /// whitespace next to it. Grouping separators are not accepted, and if the decimal separator
+    /// is not a dot, amounts containing a dot are rejected, so that a grouping separator is never
+    /// taken as a decimal one. The rest of the parsing rules, including the rounding of sub-cent
+    /// digits, are the same as in the `FromStr` implementation:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let amount = Amount::parse_with_locale("£175.64", '.', Some('£')).unwrap();
+    /// assert_eq!(amount, Amount::from_repr(175_64));
+    ///
+    /// let amount = Amount::parse_with_locale("175,64 €", ',', Some('€')).unwrap();
+    /// assert_eq!(amount, Amount::from_repr(175_64));
+    ///
+    /// assert!(Amount::parse_with_locale("1.175,64", ',', None).is_err());
+    /// ```
+    pub fn parse_with_locale(
+        s: &str,
+        decimal_separator: char,
+        symbol: Option<char>,
+    ) -> Result<Self, Error> {
+        let amount_str = s.trim();
+        let number = symbol
+            .and_then(|symbol| {
+                amount_str
+                    .strip_prefix(symbol)
+                    .or_else(|| amount_str.strip_suffix(symbol))
+            })
+            .map_or(amount_str, str::trim);
+
+        if number.starts_with('-') {
+            return Err(ParseError::Negative {
+                amount_str: amount_str.to_owned(),
+            }
+            .into());
+        }
+        if decimal_separator != '.' && number.contains('.') {
+            return Err(ParseError::Invalid {
+                amount_str: amount_str.to_owned(),
+            }
+            .into());
+        }
+
+        number.replace(decimal_separator, ".").parse().map_err(|_| {
+            ParseError::Invalid {
+                amount_str: amount_str.to_owned(),
+            }
+            .into()
+        })
+    }
+
+    /// Parses an amount, rounding the sub-cent digits with the given rounding mode.
+    ///
+    /// The `FromStr` implementation is equivalent to this method with `RoundingMode::HalfUp`:
+    ///
+    /// ```
+    /// use revolut_customer::{amount::RoundingMode, Amount};
+    ///
+    /// let amount = Amount::from_str_with_rounding("0.125", RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(amount, Amount::from_repr(0_12));
+    ///
+    /// let amount = Amount::from_str_with_rounding("0.125", RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(amount, "0.125".parse().unwrap());
+    /// ```
+    pub fn from_str_with_rounding(s: &str, rounding: RoundingMode) -> Result<Self, Error> {
+        let s = s.trim();
+        if s.starts_with('-') {
+            return Err(ParseError::Negative {
+                amount_str: s.to_owned(),
+            }
+            .into());
+        }
+        let number = s.strip_prefix('+').unwrap_or(s);
+        if number.contains(&['+', '-'][..]) {
+            return Err(ParseError::Invalid {
+                amount_str: s.to_owned(),
+            }
+            .into());
+        }
+
+        if number.contains('.') {
+            let mut split = number.split('.');
+            match (split.next(), split.next(), split.next()) {
+                (Some(units_str), Some(decimals_str), None) => {
+                    let units: u64 = if units_str.is_empty() {
+                        0
+                    } else {
+                        let u = units_str.parse::<u64>().context(ParseError::Invalid {
+                            amount_str: s.to_owned(),
+                        })?;
+                        if u <= u64::MAX / 1_00 {
+                            u * 1_00
+                        } else {
+                            return Err(ParseError::Invalid {
+                                amount_str: s.to_owned(),
+                            }
+                            .into());
+                        }
+                    };
+                    let mut decimals_str = String::from(decimals_str);
+                    if decimals_str.is_empty() {
+                        return Err(ParseError::Invalid {
+                            amount_str: s.to_owned(),
+                        }
+                        .into());
+                    }
+                    if decimals_str.len() == 1 {
+                        decimals_str.push('0');
+                    }
+                    let decimals: u64 = {
+                        let d = decimals_str.parse::<u64>().context(ParseError::Invalid {
+                            amount_str: s.to_owned(),
+                        })?;
+                        if decimals_str.len() == 2 {
+                            d
+                        } else {
+                            let divisor = u32::try_from(decimals_str.len() - 2)
+                                .ok()
+                                .and_then(|exp| 10_u64.checked_pow(exp))
+                                .ok_or_else(|| ParseError::Invalid {
+                                    amount_str: s.to_owned(),
+                                })?;
+                            rounding.div(d, divisor)
+                        }
+                    };
+
+                    if u64::MAX - decimals >= units {
+                        Ok(Self::from_repr(units + decimals))
+                    } else {
+                        Err(ParseError::Invalid {
+                            amount_str: s.to_owned(),
+                        }
+                        .into())
+                    }
+                }
+                _ => Err(ParseError::Invalid {
+                    amount_str: s.to_owned(),
+                }
+                .into()),
+            }
+        } else {
+            let units = number.parse::<u64>().context(ParseError::Invalid {
+                amount_str: s.to_owned(),
+            })?;
+
+            if units <= u64::MAX / 1_00 {
+                Ok(Self::from_repr(units * 1_00))
+            } else {
+                Err(ParseError::Invalid {
+                    amount_str: s.to_owned(),
+                }
+                .into())
+            }
+        }
+    }
+
+    /// Multiplies the amount by the given factor, rounding to the nearest cent.
+    ///
+    /// Halves are rounded up, the same way as when parsing an amount with more than two decimal
+    /// digits. The factor must be a finite, non-negative number, and the result must fit in an
+    /// amount, or a [`ConversionError`](enum.ConversionError.html) will be returned:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let amount = Amount::from_repr(10_00).mul_f64(0.333).unwrap();
+    /// assert_eq!(amount, Amount::from_repr(3_33));
+    ///
+    /// assert!(Amount::from_repr(10_00).mul_f64(-1.0).is_err());
+    /// assert!(Amount::from_repr(10_00).mul_f64(std::f64::NAN).is_err());
+    /// ```
+    ///
+    /// **Note**: The computation is performed with 64 bit floating point numbers, so amounts
+    /// bigger than 2<sup>53</sup> cents might lose precision.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn mul_f64(self, factor: f64) -> Result<Self, Error> {
+        self.mul_f64_with_rounding(factor, RoundingMode::HalfUp)
+    }
+
+    /// Multiplies the amount by the given factor, rounding the result with the given rounding
+    /// mode.
+    ///
+    /// It follows the same rules as [`mul_f64()`](#method.mul_f64), which is equivalent to this
+    /// method with `RoundingMode::HalfUp`:
+    ///
+    /// ```
+    /// use revolut_customer::{amount::RoundingMode, Amount};
+    ///
+    /// let amount = Amount::from_repr(10_00)
+    ///     .mul_f64_with_rounding(0.3335, RoundingMode::Floor)
+    ///     .unwrap();
+    /// assert_eq!(amount, Amount::from_repr(3_33));
+    ///
+    /// let amount = Amount::from_repr(10_00)
+    ///     .mul_f64_with_rounding(0.3335, RoundingMode::Ceil)
+    ///     .unwrap();
+    /// assert_eq!(amount, Amount::from_repr(3_34));
+    /// ```
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn mul_f64_with_rounding(self, factor: f64, rounding: RoundingMode) -> Result<Self, Error> {
+        if !factor.is_finite() || factor < 0.0 {
+            return Err(ConversionError::InvalidFactor { factor }.into());
+        }
+
+        let result = rounding.round_f64(self.value as f64 * factor);
+        if result < u64::MAX as f64 {
+            Ok(Self::from_repr(result as u64))
+        } else {
+            Err(ConversionError::Overflow.into())
+        }
+    }
+
+    /// Applies an exchange rate to the amount, rounding to the nearest cent.
+    ///
+    /// This is the amount that would be obtained in the target currency. It follows the same
+    /// rules as [`mul_f64()`](#method.mul_f64):
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let amount = Amount::from_repr(100_00).apply_rate(1.17).unwrap();
+    /// assert_eq!(amount, Amount::from_repr(117_00));
+    /// ```
+    pub fn apply_rate(self, rate: f64) -> Result<Self, Error> {
+        self.mul_f64(rate)
+    }
+
+    /// Applies an exchange rate to the amount, rounding the result with the given rounding mode.
+    ///
+    /// It follows the same rules as
+    /// [`mul_f64_with_rounding()`](#method.mul_f64_with_rounding):
+    ///
+    /// ```
+    /// use revolut_customer::{amount::RoundingMode, Amount};
+    ///
+    /// let amount = Amount::from_repr(0_01)
+    ///     .apply_rate_with_rounding(0.5, RoundingMode::HalfEven)
+    ///     .unwrap();
+    /// assert_eq!(amount, Amount::zero());
+    /// ```
+    pub fn apply_rate_with_rounding(
+        self,
+        rate: f64,
+        rounding: RoundingMode,
+    ) -> Result<Self, Error> {
+        self.mul_f64_with_rounding(rate, rounding)
+    }
+
+    /// Creates an amount from a floating point number of units, rounding to the nearest cent.
+    ///
+    /// Halves are rounded up. It returns `None` if the value is negative, not a number, infinite
+    /// or too big to be represented as an amount:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// assert_eq!(Amount::from_f64(1.65), Some(Amount::from_repr(1_65)));
+    /// assert_eq!(Amount::from_f64(0.005), Some(Amount::from_repr(0_01)));
+    /// assert_eq!(Amount::from_f64(-1.0), None);
+    /// ```
+    ///
+    /// **Note**: Floating point numbers can't represent most decimal amounts exactly, so values
+    /// right at a half cent might be rounded in either direction depending on their binary
+    /// representation. Amounts bigger than 2<sup>53</sup> cents will also lose precision. Parsing
+    /// a string should be preferred when the exact amount is known.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() || value < 0.0 {
+            return None;
+        }
+
+        let repr = (value * 100.0).round();
+        if repr < u64::MAX as f64 {
+            Some(Self::from_repr(repr as u64))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a zero amount.
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let amount = Amount::zero();
+    /// assert!(amount.is_zero());
+    /// assert_eq!(format!("{:.2}", amount), "0.00");
+    /// ```
+    pub const fn zero() -> Self {
+        Self { value: 0 }
+    }
+
+    /// Checks whether the amount is zero.
+    pub fn is_zero(self) -> bool {
+        self.value == 0
+    }
+
     /// Returns the smallest value that can be represented as a currency amount.
     pub const fn min_value() -> Self {
         Self {
@@ -117,6 +521,127 @@ impl Amount {
             value: u64::max_value(),
         }
     }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if overflow occurred.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.value.checked_add(rhs.value).map(Self::from_repr)
+    }
+
+    /// Checked addition of whole currency units. Computes `self + units`, returning `None` if
+    /// overflow occurred.
+    ///
+    /// The units are whole currency units, so `checked_add_units(5)` adds 5, not 0.05.
+    pub fn checked_add_units(self, units: u64) -> Option<Self> {
+        units
+            .checked_mul(1_00)
+            .and_then(|value| self.value.checked_add(value))
+            .map(Self::from_repr)
+    }
+
+    /// Checked subtraction of whole currency units. Computes `self - units`, returning `None` if
+    /// the result would be negative.
+    ///
+    /// The units are whole currency units, so `checked_sub_units(5)` subtracts 5, not 0.05.
+    pub fn checked_sub_units(self, units: u64) -> Option<Self> {
+        units
+            .checked_mul(1_00)
+            .and_then(|value| self.value.checked_sub(value))
+            .map(Self::from_repr)
+    }
+
+    /// Checked remainder. Computes `self % divisor`, returning `None` if the divisor is zero.
+    ///
+    /// As with the `%` operator, the modulo is computed in whole currency units, not in cents:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let amount = Amount::from_repr(7_34); // 7.34
+    /// assert_eq!(amount.checked_rem(1), Some(Amount::from_repr(0_34))); // 0.34
+    /// assert_eq!(amount.checked_rem(5), Some(Amount::from_repr(2_34))); // 2.34
+    /// assert_eq!(amount.checked_rem(0), None);
+    /// ```
+    ///
+    /// If the divisor is so big that it can't be represented as an amount, the amount itself is
+    /// returned, since it's always smaller than the divisor.
+    pub fn checked_rem(self, divisor: u64) -> Option<Self> {
+        if divisor == 0 {
+            None
+        } else {
+            Some(
+                divisor
+                    .checked_mul(1_00)
+                    .map_or(self, |divisor| Self::from_repr(self.value % divisor)),
+            )
+        }
+    }
+
+    /// Saturating addition. Computes `self + rhs`, saturating at the maximum amount instead of
+    /// overflowing.
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::from_repr(self.value.saturating_add(rhs.value))
+    }
+
+    /// Returns the minimum of two amounts.
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// Returns the maximum of two amounts.
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    /// Restricts the amount to the `[lo, hi]` interval.
+    ///
+    /// It returns `lo` if the amount is smaller than `lo`, `hi` if it's bigger than `hi`, and the
+    /// amount itself otherwise:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let top_up = Amount::from_repr(1_500_00);
+    /// let allowed = top_up.clamp(Amount::from_repr(10_00), Amount::from_repr(1_000_00));
+    /// assert_eq!(allowed, Amount::from_repr(1_000_00));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo` is bigger than `hi`.
+    #[must_use]
+    pub fn clamp(self, lo: Self, hi: Self) -> Self {
+        assert!(lo <= hi, "the lower bound {} is bigger than {}", lo, hi);
+        self.max(lo).min(hi)
+    }
+
+    /// Splits the amount in the given number of equal shares.
+    ///
+    /// It returns the amount of each share, and the cents that could not be distributed. Unlike
+    /// the division operator, no cent is lost, so the shares and the remainder always add up to
+    /// the original amount:
+    ///
+    /// ```
+    /// use revolut_customer::Amount;
+    ///
+    /// let (share, remainder) = Amount::from_repr(10_00).div_rem(3);
+    /// assert_eq!(share, Amount::from_repr(3_33));
+    /// assert_eq!(remainder, Amount::from_repr(1));
+    /// assert_eq!(share * 3u8 + remainder, Amount::from_repr(10_00));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    pub fn div_rem(self, divisor: u64) -> (Self, Self) {
+        assert!(divisor != 0, "cannot split an amount in zero shares");
+        (
+            Self::from_repr(self.value / divisor),
+            Self::from_repr(self.value % divisor),
+        )
+    }
 }
 
 impl fmt::Display for Amount {
@@ -174,84 +699,35 @@ impl fmt::Display for Amount {
 
 impl FromStr for Amount {
     type Err = Error;
-    #[allow(clippy::cast_possible_truncation)]
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.contains('.') {
-            let parts = s.split('.').count();
-            let mut split = s.split('.');
-            match parts {
-                2 => {
-                    let units_str = split.next().unwrap();
-                    let units: u64 = if units_str.is_empty() {
-                        0
-                    } else {
-                        let u = units_str.parse::<u64>().context(ParseError {
-                            amount_str: s.to_owned(),
-                        })?;
-                        if u <= u64::max_value() / 1_00 {
-                            u * 1_00
-                        } else {
-                            return Err(ParseError {
-                                amount_str: s.to_owned(),
-                            }
-                            .into());
-                        }
-                    };
-                    let mut decimals_str =
-                        String::from(split.next().expect("decimals disappeared"));
-                    if decimals_str.is_empty() {
-                        return Err(ParseError {
-                            amount_str: s.to_owned(),
-                        }
-                        .into());
-                    }
-                    if decimals_str.len() == 1 {
-                        decimals_str.push('0');
-                    }
-                    let decimals: u64 = {
-                        let d = decimals_str.parse::<u64>().context(ParseError {
-                            amount_str: s.to_owned(),
-                        })?;
-                        if decimals_str.len() == 2 {
-                            d
-                        } else {
-                            let divisor = 10_u64.pow(decimals_str.len() as u32 - 2);
-                            let rem = d % divisor;
-                            if rem >= divisor / 2 {
-                                d / divisor + 1
-                            } else {
-                                d / divisor
-                            }
-                        }
-                    };
+        Self::from_str_with_rounding(s, RoundingMode::HalfUp)
+    }
+}
 
-                    if u64::max_value() - decimals >= units {
-                        Ok(Self::from_repr(units + decimals))
-                    } else {
-                        Err(ParseError {
-                            amount_str: s.to_owned(),
-                        }
-                        .into())
-                    }
-                }
-                _ => Err(ParseError {
-                    amount_str: s.to_owned(),
-                }
-                .into()),
-            }
-        } else {
-            let units = s.parse::<u64>().context(ParseError {
-                amount_str: s.to_owned(),
-            })?;
+/// Lossy conversion of an amount to a floating point number of units.
+///
+/// **Note**: Amounts bigger than 2<sup>53</sup> cents can't be exactly represented, and will lose
+/// precision.
+impl From<Amount> for f64 {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(amount: Amount) -> Self {
+        amount.value as f64 / 100.0
+    }
+}
 
-            if units <= u64::max_value() / 1_00 {
-                Ok(Self::from_repr(units * 1_00))
-            } else {
-                Err(ParseError {
-                    amount_str: s.to_owned(),
-                }
-                .into())
-            }
+/// Conversion of a floating point number of units to an amount.
+///
+/// It follows the rules of [`Amount::from_f64()`](struct.Amount.html#method.from_f64), but
+/// returns a [`ConversionError`](enum.ConversionError.html) instead of `None`.
+impl TryFrom<f64> for Amount {
+    type Error = Error;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value < 0.0 {
+            Err(ConversionError::InvalidValue { value }.into())
+        } else {
+            Self::from_f64(value).ok_or_else(|| ConversionError::Overflow.into())
         }
     }
 }
@@ -287,6 +763,34 @@ macro_rules! impl_ops_int {
             }
         }
 
+        impl Add<$t> for Amount {
+            type Output = Self;
+
+            fn add(self, rhs: $t) -> Self {
+                Self { value: self.value + u64::from(rhs) * 1_00 }
+            }
+        }
+
+        impl AddAssign<$t> for Amount {
+            fn add_assign(&mut self, rhs: $t) {
+                self.value += u64::from(rhs) * 1_00
+            }
+        }
+
+        impl Sub<$t> for Amount {
+            type Output = Self;
+
+            fn sub(self, rhs: $t) -> Self {
+                Self { value: self.value - u64::from(rhs) * 1_00 }
+            }
+        }
+
+        impl SubAssign<$t> for Amount {
+            fn sub_assign(&mut self, rhs: $t) {
+                self.value -= u64::from(rhs) * 1_00
+            }
+        }
+
         impl Mul<$t> for Amount {
             type Output = Self;
 
@@ -329,6 +833,36 @@ impl AddAssign for Amount {
     }
 }
 
+/// Sums the amounts, saturating at the maximum amount instead of overflowing.
+///
+/// The sum of an empty iterator is `Amount::zero()`:
+///
+/// ```
+/// use revolut_customer::Amount;
+///
+/// let amounts = [Amount::from_repr(1_50), Amount::from_repr(2_25)];
+/// assert_eq!(amounts.iter().sum::<Amount>(), Amount::from_repr(3_75));
+/// assert_eq!(Vec::<Amount>::new().into_iter().sum::<Amount>(), Amount::zero());
+/// ```
+impl Sum for Amount {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::zero(), Self::saturating_add)
+    }
+}
+
+/// Sums the amounts, saturating at the maximum amount instead of overflowing.
+impl<'a> Sum<&'a Self> for Amount {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a Self>,
+    {
+        iter.copied().sum()
+    }
+}
+
 impl Sub for Amount {
     type Output = Self;
 
@@ -345,9 +879,354 @@ impl SubAssign for Amount {
     }
 }
 
+/// Rounding mode for the sub-cent digits of an amount.
+///
+/// Since amounts can't be negative, rounding down is the same as rounding towards zero. The
+/// default mode rounds halves up:
+///
+/// ```
+/// use revolut_customer::{amount::RoundingMode, Amount};
+///
+/// assert_eq!(RoundingMode::default(), RoundingMode::HalfUp);
+///
+/// let half_cent = "0.005";
+/// for &(rounding, repr) in &[
+///     (RoundingMode::HalfUp, 1),
+///     (RoundingMode::HalfEven, 0),
+///     (RoundingMode::Floor, 0),
+///     (RoundingMode::Ceil, 1),
+/// ] {
+///     let amount = Amount::from_str_with_rounding(half_cent, rounding).unwrap();
+///     assert_eq!(amount, Amount::from_repr(repr));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Rounds to the nearest cent, and halves up.
+    #[default]
+    HalfUp,
+    /// Rounds to the nearest cent, and halves to the nearest even cent (banker's rounding).
+    HalfEven,
+    /// Rounds down to the previous cent.
+    Floor,
+    /// Rounds up to the next cent.
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Divides the value by the divisor, rounding the quotient with this rounding mode.
+    fn div(self, value: u64, divisor: u64) -> u64 {
+        let (quotient, rem) = (value / divisor, value % divisor);
+        let round_up = match self {
+            RoundingMode::HalfUp => rem >= divisor - rem,
+            RoundingMode::HalfEven => {
+                rem > divisor - rem || (rem == divisor - rem && quotient % 2 == 1)
+            }
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => rem > 0,
+        };
+
+        if round_up {
+            quotient + 1
+        } else {
+            quotient
+        }
+    }
+
+    /// Rounds a non-negative floating point number to an integer with this rounding mode.
+    #[allow(clippy::float_cmp)]
+    fn round_f64(self, value: f64) -> f64 {
+        match self {
+            RoundingMode::HalfUp => value.round(),
+            RoundingMode::HalfEven => {
+                let floor = value.floor();
+                let diff = value - floor;
+                if diff > 0.5 || (diff == 0.5 && floor % 2.0 == 1.0) {
+                    floor + 1.0
+                } else {
+                    floor
+                }
+            }
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+        }
+    }
+}
+
+/// Direction of a signed amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The money leaves the account.
+    Debit,
+    /// The money enters the account.
+    Credit,
+}
+
+/// Signed Revolut currency amount
+///
+/// Since an `Amount` can't be negative, this type pairs it with the `Direction` of the money, so
+/// that debits and credits, such as the amounts of the transactions, can be represented. A zero
+/// amount is always a credit.
+///
+/// Signed amounts can be added and subtracted respecting their sign, and they are displayed with
+/// a leading `-` for debits:
+///
+/// ```
+/// use revolut_customer::{Amount, SignedAmount};
+///
+/// let payment = SignedAmount::debit(Amount::from_repr(25_50));
+/// let refund = SignedAmount::credit(Amount::from_repr(10_00));
+/// assert_eq!(format!("{:.2}", payment), "-25.50");
+/// assert_eq!(format!("{}", payment + refund), "-15.5");
+/// ```
+///
+/// In *JSON*, they are sent as their signed internal representation, so a debit of `25.50` is
+/// sent as `-2550`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignedAmount {
+    amount: Amount,
+    direction: Direction,
+}
+
+impl SignedAmount {
+    /// Creates a new signed amount.
+    pub fn new(amount: Amount, direction: Direction) -> Self {
+        Self {
+            amount,
+            direction: if amount.is_zero() {
+                Direction::Credit
+            } else {
+                direction
+            },
+        }
+    }
+
+    /// Creates a new credit.
+    pub fn credit(amount: Amount) -> Self {
+        Self::new(amount, Direction::Credit)
+    }
+
+    /// Creates a new debit.
+    pub fn debit(amount: Amount) -> Self {
+        Self::new(amount, Direction::Debit)
+    }
+
+    /// Creates a new signed amount from its signed internal representation.
+    pub fn from_repr(value: i64) -> Self {
+        let direction = if value < 0 {
+            Direction::Debit
+        } else {
+            Direction::Credit
+        };
+        Self::new(Amount::from_repr(value.unsigned_abs()), direction)
+    }
+
+    /// Gets the signed internal representation of the amount.
+    ///
+    /// It returns `None` if the amount does not fit in an `i64`.
+    pub fn get_repr(self) -> Option<i64> {
+        let value = i128::from(self.amount.get_repr());
+        let value = match self.direction {
+            Direction::Debit => -value,
+            Direction::Credit => value,
+        };
+        i64::try_from(value).ok()
+    }
+
+    /// Gets the absolute amount.
+    pub fn amount(self) -> Amount {
+        self.amount
+    }
+
+    /// Gets the direction of the amount.
+    pub fn direction(self) -> Direction {
+        self.direction
+    }
+
+    /// Checks whether the amount is a debit.
+    pub fn is_debit(self) -> bool {
+        self.direction == Direction::Debit
+    }
+
+    /// Checks whether the amount is a credit.
+    pub fn is_credit(self) -> bool {
+        self.direction == Direction::Credit
+    }
+}
+
+impl From<Amount> for SignedAmount {
+    fn from(amount: Amount) -> Self {
+        Self::credit(amount)
+    }
+}
+
+impl fmt::Display for SignedAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let amount = match f.precision() {
+            Some(precision) => format!("{:.*}", precision, self.amount),
+            None => format!("{}", self.amount),
+        };
+        f.pad_integral(self.is_credit(), "", &amount)
+    }
+}
+
+impl Neg for SignedAmount {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self.direction {
+            Direction::Debit => Self::credit(self.amount),
+            Direction::Credit => Self::debit(self.amount),
+        }
+    }
+}
+
+impl Add for SignedAmount {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if self.direction == rhs.direction {
+            Self::new(self.amount + rhs.amount, self.direction)
+        } else if self.amount >= rhs.amount {
+            Self::new(self.amount - rhs.amount, self.direction)
+        } else {
+            Self::new(rhs.amount - self.amount, rhs.direction)
+        }
+    }
+}
+
+impl AddAssign for SignedAmount {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for SignedAmount {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl SubAssign for SignedAmount {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl Serialize for SignedAmount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = self
+            .get_repr()
+            .ok_or_else(|| ser::Error::custom("the amount does not fit in a signed integer"))?;
+        serializer.serialize_i64(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignedAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(Self::from_repr)
+    }
+}
+
+/// Serialization of amounts as decimal strings.
+///
+/// By default, amounts are serialized as their internal representation, since that's the format
+/// used by the Revolut API. This module can be used with `#[serde(with = "...")]` to serialize
+/// them as human readable decimal strings instead, with two decimal digits. When deserializing,
+/// any amount accepted by the `FromStr` implementation is accepted, rounding sub-cent digits:
+///
+/// ```
+/// use revolut_customer::Amount;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Export {
+///     #[serde(with = "revolut_customer::amount::as_decimal_string")]
+///     amount: Amount,
+/// }
+///
+/// let export = Export { amount: Amount::from_repr(175_64) };
+/// assert_eq!(serde_json::to_string(&export).unwrap(), r#"{"amount":"175.64"}"#);
+/// ```
+pub mod as_decimal_string {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::Amount;
+
+    /// Serializes the amount as a decimal string.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S>(amount: &Amount, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("{:.2}", amount))
+    }
+
+    /// Deserializes the amount from a decimal string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Amount, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let amount_str = String::deserialize(deserializer)?;
+        amount_str.parse().map_err(de::Error::custom)
+    }
+}
+
 /// Revolut amount parsing error.
-#[derive(Debug, Clone, Fail, PartialEq)]
-#[fail(display = "the amount {} is not a valid Revolut amount", amount_str)]
-pub struct ParseError {
-    pub(crate) amount_str: String,
+///
+/// It's `#[non_exhaustive]`, so that more specific parsing errors can be added without breaking
+/// the code matching on it.
+#[derive(Debug, Clone, thiserror::Error, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The amount is not correctly formatted.
+    #[error("the amount {amount_str} is not a valid Revolut amount")]
+    Invalid {
+        /// The amount that could not be parsed.
+        amount_str: String,
+    },
+    /// The amount is negative.
+    #[error("the amount {amount_str} is negative, and Revolut amounts can't be negative")]
+    Negative {
+        /// The amount that could not be parsed.
+        amount_str: String,
+    },
+}
+
+/// Revolut amount conversion error.
+///
+/// It's `#[non_exhaustive]`, since new conversions may fail in new ways.
+#[derive(Debug, Clone, Copy, thiserror::Error, PartialEq)]
+#[allow(variant_size_differences)]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// The minor units are not between 0 and 99.
+    #[error("the minor units ({minor}) must be between 0 and 99")]
+    InvalidMinor {
+        /// The provided minor units.
+        minor: u8,
+    },
+    /// The amount is bigger than the maximum Revolut amount.
+    #[error("the amount is bigger than the maximum Revolut amount")]
+    Overflow,
+    /// The factor is negative, infinite or not a number.
+    #[error("the factor ({factor}) must be a finite, non-negative number")]
+    InvalidFactor {
+        /// The provided factor.
+        factor: f64,
+    },
+    /// The floating point value is negative, infinite or not a number.
+    #[error("the value ({value}) must be a finite, non-negative number")]
+    InvalidValue {
+        /// The provided value.
+        value: f64,
+    },
 }
@@ -0,0 +1,595 @@
+//! Country representation
+//!
+//! This module holds the `Country` type, with the countries in the
+//! [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) standard, which is the
+//! one used by the Revolut API for the countries of addresses and card issuers:
+//!
+//! ```
+//! use revolut_customer::Country;
+//!
+//! let country = Country::from("FR");
+//! assert_eq!(country, Country::France);
+//! assert_eq!(format!("{}", country), "FR");
+//!
+//! assert_eq!(Country::from("XX"), Country::Unknown("XX".to_owned()));
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Defines the `Country` enumeration, and its conversions from and to the ISO 3166-1 alpha-2
+/// codes.
+macro_rules! countries {
+    ($($(#[$doc:meta])* $variant:ident => $code:expr,)*) => {
+        /// Country.
+        ///
+        /// Codes not known by this crate are kept in the `Unknown` variant, so that a new code in
+        /// the API does not break the deserialization of the structures containing them. Codes
+        /// are matched case-insensitively.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[allow(clippy::doc_markdown)]
+        pub enum Country {
+            $($(#[$doc])* $variant,)*
+            /// Unknown country.
+            Unknown(String),
+        }
+
+        impl Country {
+            /// Gets the ISO 3166-1 alpha-2 code of the country.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Country::$variant => $code,)*
+                    Country::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for Country {
+            fn from(code: &str) -> Self {
+                match code.to_uppercase().as_str() {
+                    $($code => Country::$variant,)*
+                    _ => Country::Unknown(code.to_owned()),
+                }
+            }
+        }
+    };
+}
+
+countries! {
+    /// Andorra (`AD`).
+    Andorra => "AD",
+    /// United Arab Emirates (`AE`).
+    UnitedArabEmirates => "AE",
+    /// Afghanistan (`AF`).
+    Afghanistan => "AF",
+    /// Antigua and Barbuda (`AG`).
+    AntiguaAndBarbuda => "AG",
+    /// Anguilla (`AI`).
+    Anguilla => "AI",
+    /// Albania (`AL`).
+    Albania => "AL",
+    /// Armenia (`AM`).
+    Armenia => "AM",
+    /// Angola (`AO`).
+    Angola => "AO",
+    /// Antarctica (`AQ`).
+    Antarctica => "AQ",
+    /// Argentina (`AR`).
+    Argentina => "AR",
+    /// American Samoa (`AS`).
+    AmericanSamoa => "AS",
+    /// Austria (`AT`).
+    Austria => "AT",
+    /// Australia (`AU`).
+    Australia => "AU",
+    /// Aruba (`AW`).
+    Aruba => "AW",
+    /// Åland Islands (`AX`).
+    AlandIslands => "AX",
+    /// Azerbaijan (`AZ`).
+    Azerbaijan => "AZ",
+    /// Bosnia and Herzegovina (`BA`).
+    BosniaAndHerzegovina => "BA",
+    /// Barbados (`BB`).
+    Barbados => "BB",
+    /// Bangladesh (`BD`).
+    Bangladesh => "BD",
+    /// Belgium (`BE`).
+    Belgium => "BE",
+    /// Burkina Faso (`BF`).
+    BurkinaFaso => "BF",
+    /// Bulgaria (`BG`).
+    Bulgaria => "BG",
+    /// Bahrain (`BH`).
+    Bahrain => "BH",
+    /// Burundi (`BI`).
+    Burundi => "BI",
+    /// Benin (`BJ`).
+    Benin => "BJ",
+    /// Saint Barthélemy (`BL`).
+    SaintBarthelemy => "BL",
+    /// Bermuda (`BM`).
+    Bermuda => "BM",
+    /// Brunei (`BN`).
+    Brunei => "BN",
+    /// Bolivia (`BO`).
+    Bolivia => "BO",
+    /// Bonaire, Sint Eustatius and Saba (`BQ`).
+    BonaireSintEustatiusAndSaba => "BQ",
+    /// Brazil (`BR`).
+    Brazil => "BR",
+    /// Bahamas (`BS`).
+    Bahamas => "BS",
+    /// Bhutan (`BT`).
+    Bhutan => "BT",
+    /// Bouvet Island (`BV`).
+    BouvetIsland => "BV",
+    /// Botswana (`BW`).
+    Botswana => "BW",
+    /// Belarus (`BY`).
+    Belarus => "BY",
+    /// Belize (`BZ`).
+    Belize => "BZ",
+    /// Canada (`CA`).
+    Canada => "CA",
+    /// Cocos (Keeling) Islands (`CC`).
+    CocosIslands => "CC",
+    /// Democratic Republic of the Congo (`CD`).
+    DemocraticRepublicOfTheCongo => "CD",
+    /// Central African Republic (`CF`).
+    CentralAfricanRepublic => "CF",
+    /// Congo (`CG`).
+    Congo => "CG",
+    /// Switzerland (`CH`).
+    Switzerland => "CH",
+    /// Côte d'Ivoire (`CI`).
+    IvoryCoast => "CI",
+    /// Cook Islands (`CK`).
+    CookIslands => "CK",
+    /// Chile (`CL`).
+    Chile => "CL",
+    /// Cameroon (`CM`).
+    Cameroon => "CM",
+    /// China (`CN`).
+    China => "CN",
+    /// Colombia (`CO`).
+    Colombia => "CO",
+    /// Costa Rica (`CR`).
+    CostaRica => "CR",
+    /// Cuba (`CU`).
+    Cuba => "CU",
+    /// Cabo Verde (`CV`).
+    CaboVerde => "CV",
+    /// Curaçao (`CW`).
+    Curacao => "CW",
+    /// Christmas Island (`CX`).
+    ChristmasIsland => "CX",
+    /// Cyprus (`CY`).
+    Cyprus => "CY",
+    /// Czechia (`CZ`).
+    Czechia => "CZ",
+    /// Germany (`DE`).
+    Germany => "DE",
+    /// Djibouti (`DJ`).
+    Djibouti => "DJ",
+    /// Denmark (`DK`).
+    Denmark => "DK",
+    /// Dominica (`DM`).
+    Dominica => "DM",
+    /// Dominican Republic (`DO`).
+    DominicanRepublic => "DO",
+    /// Algeria (`DZ`).
+    Algeria => "DZ",
+    /// Ecuador (`EC`).
+    Ecuador => "EC",
+    /// Estonia (`EE`).
+    Estonia => "EE",
+    /// Egypt (`EG`).
+    Egypt => "EG",
+    /// Western Sahara (`EH`).
+    WesternSahara => "EH",
+    /// Eritrea (`ER`).
+    Eritrea => "ER",
+    /// Spain (`ES`).
+    Spain => "ES",
+    /// Ethiopia (`ET`).
+    Ethiopia => "ET",
+    /// Finland (`FI`).
+    Finland => "FI",
+    /// Fiji (`FJ`).
+    Fiji => "FJ",
+    /// Falkland Islands (`FK`).
+    FalklandIslands => "FK",
+    /// Micronesia (`FM`).
+    Micronesia => "FM",
+    /// Faroe Islands (`FO`).
+    FaroeIslands => "FO",
+    /// France (`FR`).
+    France => "FR",
+    /// Gabon (`GA`).
+    Gabon => "GA",
+    /// United Kingdom (`GB`).
+    UnitedKingdom => "GB",
+    /// Grenada (`GD`).
+    Grenada => "GD",
+    /// Georgia (`GE`).
+    Georgia => "GE",
+    /// French Guiana (`GF`).
+    FrenchGuiana => "GF",
+    /// Guernsey (`GG`).
+    Guernsey => "GG",
+    /// Ghana (`GH`).
+    Ghana => "GH",
+    /// Gibraltar (`GI`).
+    Gibraltar => "GI",
+    /// Greenland (`GL`).
+    Greenland => "GL",
+    /// Gambia (`GM`).
+    Gambia => "GM",
+    /// Guinea (`GN`).
+    Guinea => "GN",
+    /// Guadeloupe (`GP`).
+    Guadeloupe => "GP",
+    /// Equatorial Guinea (`GQ`).
+    EquatorialGuinea => "GQ",
+    /// Greece (`GR`).
+    Greece => "GR",
+    /// South Georgia and the South Sandwich Islands (`GS`).
+    SouthGeorgiaAndTheSouthSandwichIslands => "GS",
+    /// Guatemala (`GT`).
+    Guatemala => "GT",
+    /// Guam (`GU`).
+    Guam => "GU",
+    /// Guinea-Bissau (`GW`).
+    GuineaBissau => "GW",
+    /// Guyana (`GY`).
+    Guyana => "GY",
+    /// Hong Kong (`HK`).
+    HongKong => "HK",
+    /// Heard Island and McDonald Islands (`HM`).
+    HeardIslandAndMcDonaldIslands => "HM",
+    /// Honduras (`HN`).
+    Honduras => "HN",
+    /// Croatia (`HR`).
+    Croatia => "HR",
+    /// Haiti (`HT`).
+    Haiti => "HT",
+    /// Hungary (`HU`).
+    Hungary => "HU",
+    /// Indonesia (`ID`).
+    Indonesia => "ID",
+    /// Ireland (`IE`).
+    Ireland => "IE",
+    /// Israel (`IL`).
+    Israel => "IL",
+    /// Isle of Man (`IM`).
+    IsleOfMan => "IM",
+    /// India (`IN`).
+    India => "IN",
+    /// British Indian Ocean Territory (`IO`).
+    BritishIndianOceanTerritory => "IO",
+    /// Iraq (`IQ`).
+    Iraq => "IQ",
+    /// Iran (`IR`).
+    Iran => "IR",
+    /// Iceland (`IS`).
+    Iceland => "IS",
+    /// Italy (`IT`).
+    Italy => "IT",
+    /// Jersey (`JE`).
+    Jersey => "JE",
+    /// Jamaica (`JM`).
+    Jamaica => "JM",
+    /// Jordan (`JO`).
+    Jordan => "JO",
+    /// Japan (`JP`).
+    Japan => "JP",
+    /// Kenya (`KE`).
+    Kenya => "KE",
+    /// Kyrgyzstan (`KG`).
+    Kyrgyzstan => "KG",
+    /// Cambodia (`KH`).
+    Cambodia => "KH",
+    /// Kiribati (`KI`).
+    Kiribati => "KI",
+    /// Comoros (`KM`).
+    Comoros => "KM",
+    /// Saint Kitts and Nevis (`KN`).
+    SaintKittsAndNevis => "KN",
+    /// North Korea (`KP`).
+    NorthKorea => "KP",
+    /// South Korea (`KR`).
+    SouthKorea => "KR",
+    /// Kuwait (`KW`).
+    Kuwait => "KW",
+    /// Cayman Islands (`KY`).
+    CaymanIslands => "KY",
+    /// Kazakhstan (`KZ`).
+    Kazakhstan => "KZ",
+    /// Laos (`LA`).
+    Laos => "LA",
+    /// Lebanon (`LB`).
+    Lebanon => "LB",
+    /// Saint Lucia (`LC`).
+    SaintLucia => "LC",
+    /// Liechtenstein (`LI`).
+    Liechtenstein => "LI",
+    /// Sri Lanka (`LK`).
+    SriLanka => "LK",
+    /// Liberia (`LR`).
+    Liberia => "LR",
+    /// Lesotho (`LS`).
+    Lesotho => "LS",
+    /// Lithuania (`LT`).
+    Lithuania => "LT",
+    /// Luxembourg (`LU`).
+    Luxembourg => "LU",
+    /// Latvia (`LV`).
+    Latvia => "LV",
+    /// Libya (`LY`).
+    Libya => "LY",
+    /// Morocco (`MA`).
+    Morocco => "MA",
+    /// Monaco (`MC`).
+    Monaco => "MC",
+    /// Moldova (`MD`).
+    Moldova => "MD",
+    /// Montenegro (`ME`).
+    Montenegro => "ME",
+    /// Saint Martin (French part) (`MF`).
+    SaintMartin => "MF",
+    /// Madagascar (`MG`).
+    Madagascar => "MG",
+    /// Marshall Islands (`MH`).
+    MarshallIslands => "MH",
+    /// North Macedonia (`MK`).
+    NorthMacedonia => "MK",
+    /// Mali (`ML`).
+    Mali => "ML",
+    /// Myanmar (`MM`).
+    Myanmar => "MM",
+    /// Mongolia (`MN`).
+    Mongolia => "MN",
+    /// Macao (`MO`).
+    Macao => "MO",
+    /// Northern Mariana Islands (`MP`).
+    NorthernMarianaIslands => "MP",
+    /// Martinique (`MQ`).
+    Martinique => "MQ",
+    /// Mauritania (`MR`).
+    Mauritania => "MR",
+    /// Montserrat (`MS`).
+    Montserrat => "MS",
+    /// Malta (`MT`).
+    Malta => "MT",
+    /// Mauritius (`MU`).
+    Mauritius => "MU",
+    /// Maldives (`MV`).
+    Maldives => "MV",
+    /// Malawi (`MW`).
+    Malawi => "MW",
+    /// Mexico (`MX`).
+    Mexico => "MX",
+    /// Malaysia (`MY`).
+    Malaysia => "MY",
+    /// Mozambique (`MZ`).
+    Mozambique => "MZ",
+    /// Namibia (`NA`).
+    Namibia => "NA",
+    /// New Caledonia (`NC`).
+    NewCaledonia => "NC",
+    /// Niger (`NE`).
+    Niger => "NE",
+    /// Norfolk Island (`NF`).
+    NorfolkIsland => "NF",
+    /// Nigeria (`NG`).
+    Nigeria => "NG",
+    /// Nicaragua (`NI`).
+    Nicaragua => "NI",
+    /// Netherlands (`NL`).
+    Netherlands => "NL",
+    /// Norway (`NO`).
+    Norway => "NO",
+    /// Nepal (`NP`).
+    Nepal => "NP",
+    /// Nauru (`NR`).
+    Nauru => "NR",
+    /// Niue (`NU`).
+    Niue => "NU",
+    /// New Zealand (`NZ`).
+    NewZealand => "NZ",
+    /// Oman (`OM`).
+    Oman => "OM",
+    /// Panama (`PA`).
+    Panama => "PA",
+    /// Peru (`PE`).
+    Peru => "PE",
+    /// French Polynesia (`PF`).
+    FrenchPolynesia => "PF",
+    /// Papua New Guinea (`PG`).
+    PapuaNewGuinea => "PG",
+    /// Philippines (`PH`).
+    Philippines => "PH",
+    /// Pakistan (`PK`).
+    Pakistan => "PK",
+    /// Poland (`PL`).
+    Poland => "PL",
+    /// Saint Pierre and Miquelon (`PM`).
+    SaintPierreAndMiquelon => "PM",
+    /// Pitcairn Islands (`PN`).
+    PitcairnIslands => "PN",
+    /// Puerto Rico (`PR`).
+    PuertoRico => "PR",
+    /// Palestine (`PS`).
+    Palestine => "PS",
+    /// Portugal (`PT`).
+    Portugal => "PT",
+    /// Palau (`PW`).
+    Palau => "PW",
+    /// Paraguay (`PY`).
+    Paraguay => "PY",
+    /// Qatar (`QA`).
+    Qatar => "QA",
+    /// Réunion (`RE`).
+    Reunion => "RE",
+    /// Romania (`RO`).
+    Romania => "RO",
+    /// Serbia (`RS`).
+    Serbia => "RS",
+    /// Russia (`RU`).
+    Russia => "RU",
+    /// Rwanda (`RW`).
+    Rwanda => "RW",
+    /// Saudi Arabia (`SA`).
+    SaudiArabia => "SA",
+    /// Solomon Islands (`SB`).
+    SolomonIslands => "SB",
+    /// Seychelles (`SC`).
+    Seychelles => "SC",
+    /// Sudan (`SD`).
+    Sudan => "SD",
+    /// Sweden (`SE`).
+    Sweden => "SE",
+    /// Singapore (`SG`).
+    Singapore => "SG",
+    /// Saint Helena, Ascension and Tristan da Cunha (`SH`).
+    SaintHelena => "SH",
+    /// Slovenia (`SI`).
+    Slovenia => "SI",
+    /// Svalbard and Jan Mayen (`SJ`).
+    SvalbardAndJanMayen => "SJ",
+    /// Slovakia (`SK`).
+    Slovakia => "SK",
+    /// Sierra Leone (`SL`).
+    SierraLeone => "SL",
+    /// San Marino (`SM`).
+    SanMarino => "SM",
+    /// Senegal (`SN`).
+    Senegal => "SN",
+    /// Somalia (`SO`).
+    Somalia => "SO",
+    /// Suriname (`SR`).
+    Suriname => "SR",
+    /// South Sudan (`SS`).
+    SouthSudan => "SS",
+    /// Sao Tome and Principe (`ST`).
+    SaoTomeAndPrincipe => "ST",
+    /// El Salvador (`SV`).
+    ElSalvador => "SV",
+    /// Sint Maarten (Dutch part) (`SX`).
+    SintMaarten => "SX",
+    /// Syria (`SY`).
+    Syria => "SY",
+    /// Eswatini (`SZ`).
+    Eswatini => "SZ",
+    /// Turks and Caicos Islands (`TC`).
+    TurksAndCaicosIslands => "TC",
+    /// Chad (`TD`).
+    Chad => "TD",
+    /// French Southern Territories (`TF`).
+    FrenchSouthernTerritories => "TF",
+    /// Togo (`TG`).
+    Togo => "TG",
+    /// Thailand (`TH`).
+    Thailand => "TH",
+    /// Tajikistan (`TJ`).
+    Tajikistan => "TJ",
+    /// Tokelau (`TK`).
+    Tokelau => "TK",
+    /// Timor-Leste (`TL`).
+    TimorLeste => "TL",
+    /// Turkmenistan (`TM`).
+    Turkmenistan => "TM",
+    /// Tunisia (`TN`).
+    Tunisia => "TN",
+    /// Tonga (`TO`).
+    Tonga => "TO",
+    /// Turkey (`TR`).
+    Turkey => "TR",
+    /// Trinidad and Tobago (`TT`).
+    TrinidadAndTobago => "TT",
+    /// Tuvalu (`TV`).
+    Tuvalu => "TV",
+    /// Taiwan (`TW`).
+    Taiwan => "TW",
+    /// Tanzania (`TZ`).
+    Tanzania => "TZ",
+    /// Ukraine (`UA`).
+    Ukraine => "UA",
+    /// Uganda (`UG`).
+    Uganda => "UG",
+    /// United States Minor Outlying Islands (`UM`).
+    UnitedStatesMinorOutlyingIslands => "UM",
+    /// United States (`US`).
+    UnitedStates => "US",
+    /// Uruguay (`UY`).
+    Uruguay => "UY",
+    /// Uzbekistan (`UZ`).
+    Uzbekistan => "UZ",
+    /// Holy See (`VA`).
+    HolySee => "VA",
+    /// Saint Vincent and the Grenadines (`VC`).
+    SaintVincentAndTheGrenadines => "VC",
+    /// Venezuela (`VE`).
+    Venezuela => "VE",
+    /// British Virgin Islands (`VG`).
+    BritishVirginIslands => "VG",
+    /// United States Virgin Islands (`VI`).
+    UnitedStatesVirginIslands => "VI",
+    /// Vietnam (`VN`).
+    Vietnam => "VN",
+    /// Vanuatu (`VU`).
+    Vanuatu => "VU",
+    /// Wallis and Futuna (`WF`).
+    WallisAndFutuna => "WF",
+    /// Samoa (`WS`).
+    Samoa => "WS",
+    /// Yemen (`YE`).
+    Yemen => "YE",
+    /// Mayotte (`YT`).
+    Mayotte => "YT",
+    /// South Africa (`ZA`).
+    SouthAfrica => "ZA",
+    /// Zambia (`ZM`).
+    Zambia => "ZM",
+    /// Zimbabwe (`ZW`).
+    Zimbabwe => "ZW",
+}
+
+impl From<String> for Country {
+    fn from(code: String) -> Self {
+        Self::from(code.as_str())
+    }
+}
+
+impl AsRef<str> for Country {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for Country {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(de)?;
+        Ok(Self::from(code.as_str()))
+    }
+}
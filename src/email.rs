@@ -0,0 +1,120 @@
+//! Email address representation
+//!
+//! This module holds the `Email` type and its `ParseError`. Only a basic syntactic validation is
+//! performed: the address must contain a single `@` sign, with non-empty local and domain parts:
+//!
+//! ```
+//! use revolut_customer::Email;
+//!
+//! let email = "john.doe@example.com".parse::<Email>().unwrap();
+//! assert_eq!(email.local_part(), Some("john.doe"));
+//! assert_eq!(email.domain(), Some("example.com"));
+//!
+//! assert!("john.doe".parse::<Email>().is_err());
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use anyhow::Error;
+use serde::{Deserialize, Deserializer};
+
+/// Email address.
+///
+/// Parsing only checks the shape of the address, a single `@` sign between a non-empty local part
+/// and a non-empty domain. Whether the address can receive emails is up to the API, that tracks
+/// it in [`User::email_verified()`](../private/struct.User.html#method.email_verified).
+///
+/// Addresses received from the API are kept as they are. If they don't have the expected shape,
+/// [`is_valid()`](#method.is_valid) returns `false`, and the local part and domain are not
+/// available.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Email {
+    /// The email address.
+    address: String,
+    /// Position of the `@` sign, if the email address is valid.
+    at: Option<usize>,
+}
+
+impl Email {
+    /// Gets the email address as a string.
+    pub fn as_str(&self) -> &str {
+        &self.address
+    }
+
+    /// Checks whether the email address is syntactically valid.
+    pub fn is_valid(&self) -> bool {
+        self.at.is_some()
+    }
+
+    /// Gets the local part of the email address, before the `@` sign.
+    ///
+    /// It will return `None` if the email address is not valid.
+    pub fn local_part(&self) -> Option<&str> {
+        self.at.map(|at| &self.address[..at])
+    }
+
+    /// Gets the domain of the email address, after the `@` sign.
+    ///
+    /// It will return `None` if the email address is not valid.
+    pub fn domain(&self) -> Option<&str> {
+        self.at.map(|at| &self.address[at + 1..])
+    }
+}
+
+/// Gets the position of the `@` sign of the given email address, if it's valid.
+fn at_position(address: &str) -> Option<usize> {
+    let at = address.find('@')?;
+    if at > 0 && at < address.len() - 1 && !address[at + 1..].contains('@') {
+        Some(at)
+    } else {
+        None
+    }
+}
+
+impl FromStr for Email {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(at) = at_position(s) {
+            Ok(Self {
+                address: s.to_owned(),
+                at: Some(at),
+            })
+        } else {
+            Err(ParseError {
+                email_str: s.to_owned(),
+            }
+            .into())
+        }
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        &self.address
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+impl<'de> Deserialize<'de> for Email {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let address = String::deserialize(de)?;
+        let at = at_position(&address);
+        Ok(Self { address, at })
+    }
+}
+
+/// Email address parsing error.
+#[derive(Debug, Clone, thiserror::Error, PartialEq)]
+#[error("the email address {email_str} is not valid")]
+pub struct ParseError {
+    pub(crate) email_str: String,
+}
@@ -6,7 +6,7 @@
 //! The HTTP API is documented for each method in the [`Client`](struct.Client.html) type.
 //!
 //! ```no_run
-//! # use failure::Error;
+//! # use anyhow::Error;
 //! use revolut_customer::Client;
 //!
 //! # fn main() -> Result<(), Error> {
@@ -19,6 +19,26 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! The TLS backend of the HTTP client can be selected with features. The `rustls-tls` feature,
+//! enabled by default, uses [`rustls`](https://docs.rs/rustls), so that no system TLS library is
+//! needed. The `native-tls` feature uses the native TLS library of the platform (OpenSSL on
+//! Linux) instead. One of them must be enabled to connect to the API.
+//!
+//! The internal HTTP client asks the API for gzip compressed responses, and decompresses them
+//! transparently, which greatly reduces the size of long transaction lists. Other encodings, such
+//! as deflate or brotli, are not supported by the HTTP client, so they are never requested.
+//!
+//! WebAssembly targets, such as `wasm32-unknown-unknown`, are not supported. The client is
+//! blocking, and the version of `reqwest` it uses can't be compiled to WebAssembly, so supporting
+//! them would require an asynchronous client built on a newer `reqwest` release.
+//!
+//! If the `tracing` feature is enabled, the requests sent to the API and the status codes of their
+//! responses are logged using the [`tracing`](https://docs.rs/tracing) crate. Neither the headers
+//! nor the bodies are logged, so access tokens and passwords never end up in the logs.
+//!
+//! If the `env` feature is enabled, an authenticated client can be created from environment
+//! variables with [`Client::from_env()`](struct.Client.html#method.from_env).
 
 #![forbid(anonymous_parameters)]
 #![warn(clippy::pedantic)]
@@ -41,18 +61,40 @@
 #![allow(clippy::default_trait_access)]
 
 pub mod amount;
+pub mod country;
+pub mod email;
+pub mod money;
+pub mod phone;
+pub mod prelude;
 pub mod private;
-mod public;
+pub mod public;
+pub mod shared;
+pub mod transport;
+
+use std::{convert::TryFrom, env, fmt, fs, io, path::Path, sync::Arc, time::Duration};
 
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
 use derive_builder::Builder;
-use failure::{Error, Fail, ResultExt};
-use getset::{Getters, Setters};
+use getset::{CopyGetters, Getters, Setters};
 use lazy_static::lazy_static;
-use reqwest::{RequestBuilder, StatusCode, Url};
-use serde::Deserialize;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_LANGUAGE, RETRY_AFTER, USER_AGENT},
+    Proxy, RequestBuilder, Response, StatusCode, Url,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use uuid::Uuid;
 
-pub use crate::amount::Amount;
+pub use crate::{
+    amount::{Amount, SignedAmount},
+    country::Country,
+    email::Email,
+    money::Money,
+    phone::Phone,
+    public::{CountryInfo, CurrencyInfo},
+    shared::SharedClient,
+    transport::Transport,
+};
 
 lazy_static! {
     /// Base URL for the API.
@@ -61,44 +103,169 @@ lazy_static! {
 }
 
 /// API error.
-#[derive(Debug, Clone, Fail, PartialEq)]
+///
+/// New variants will be added as more API errors are identified, so this enum is marked as
+/// `#[non_exhaustive]`, and matches on it must include a wildcard arm.
+#[derive(Debug, Clone, thiserror::Error, PartialEq)]
 #[allow(variant_size_differences)]
+#[non_exhaustive]
 pub enum ApiError {
     /// Unauthorized use of the API.
-    #[fail(display = "unauthorized use of the API")]
+    #[error("unauthorized use of the API")]
     Unauthorized,
+    /// The access token has expired, and the user must sign in again.
+    #[error("the access token has expired")]
+    TokenExpired,
     /// The client had not logged in.
-    #[fail(display = "the client had not logged in")]
+    #[error("the client had not logged in")]
     NotLoggedIn,
     /// Invalid user ID.
-    #[fail(display = "the provided user ID is not a valid UUID")]
+    #[error("the provided user ID is not a valid UUID")]
     InvalidUserId,
+    /// Only one of the user ID and the access token was provided.
+    #[error("both the user ID and the access token must be provided")]
+    IncompleteAuth,
+    /// Invalid proxy URL.
+    #[error("the provided proxy URL is not valid")]
+    InvalidProxy,
+    /// Invalid base URL of the API.
+    #[error("the provided base URL can't have paths appended to it")]
+    InvalidBaseUrl,
+    /// Invalid phone number.
+    #[error("the provided phone number is not a valid E.164 phone number")]
+    InvalidPhone,
+    /// Invalid email address.
+    #[error("the provided email address is not valid")]
+    InvalidEmail,
+    /// Invalid passcode.
+    #[error("the provided passcode is not valid")]
+    InvalidPasscode,
+    /// Invalid pagination cursor.
+    #[error("the provided pagination cursor is not valid")]
+    InvalidCursor,
+    /// Invalid auto-topup configuration.
+    #[error("the auto-topup threshold and amount must be provided together")]
+    InvalidAutoTopup,
+    /// Required environment variables were not set.
+    #[error("missing environment variables: {}", .vars.join(", "))]
+    MissingEnvVars {
+        /// Names of the missing variables.
+        vars: Vec<&'static str>,
+    },
+    /// An environment variable did not have a valid value.
+    #[error("the environment variable {name} is not valid")]
+    InvalidEnvVar {
+        /// Name of the variable.
+        name: &'static str,
+    },
+    /// The requested resource was not found.
+    #[error("the requested resource was not found")]
+    NotFound,
     /// Failure performing the request.
-    #[fail(display = "failure performing the request")]
+    #[error("failure performing the request")]
     RequestFailure,
     /// The request was not correctly formed.
-    #[fail(
-        display = "the request was not correctly formed. (message: {}, code: {:?})",
-        message, code
-    )]
+    #[error("the request was not correctly formed. (message: {message}, code: {code:?})")]
     BadRequest {
         /// Error description.
         message: String,
         /// Revolut's error code
         code: Option<i32>,
     },
+    /// Too many requests were sent to the API, and the client must back off.
+    #[error("too many requests sent to the API (retry after: {retry_after:?})")]
+    RateLimited {
+        /// Time to wait before retrying the request, if the API provided it.
+        retry_after: Option<Duration>,
+    },
     /// The request failed for an unknown reason.
-    #[fail(
-        display = "request failed for an unknown reason (status code: {})",
-        status_code
-    )]
+    #[error("request failed for an unknown reason (status code: {status_code})")]
     Other {
         /// Status code of the API response.
         status_code: StatusCode,
+        /// Body of the API response, if it could be read.
+        body: Option<String>,
     },
     /// Error parsing the API response.
-    #[fail(display = "could not parse the response")]
+    #[error("could not parse the response")]
     ParseResponse,
+    /// The API response had no body, but the method expected one.
+    #[error("the response had an empty body")]
+    EmptyResponse,
+}
+
+impl ApiError {
+    /// Gets the error for an unauthorized (`401`) response of the API.
+    ///
+    /// The API does not send an error code for an expired access token, so `TokenExpired` will
+    /// only be returned if the error message of the response body is exactly the one the API
+    /// sends in that case. Any other `401` response, including the ones with a message
+    /// translated to the locale of the options, will be returned as `Unauthorized`.
+    pub(crate) fn unauthorized(response: &mut Response) -> Self {
+        match response.json::<ErrResponse>() {
+            Ok(ref err_response) if err_response.message == TOKEN_EXPIRED_MESSAGE => {
+                ApiError::TokenExpired
+            }
+            _ => ApiError::Unauthorized,
+        }
+    }
+
+    /// Gets the error for a rate limited (`429`) response of the API.
+    ///
+    /// The time to wait is read from the `Retry-After` header, that can contain either a number
+    /// of seconds or an HTTP date. If the header is absent or not valid, no time will be provided.
+    pub(crate) fn rate_limited(response: &Response) -> Self {
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+
+        ApiError::RateLimited { retry_after }
+    }
+}
+
+/// Error message of the API for an expired access token.
+const TOKEN_EXPIRED_MESSAGE: &str = "The access token has expired";
+
+/// Extension trait to inspect the API errors returned by the client methods.
+///
+/// The client methods return an `anyhow::Error`, and this trait avoids downcasting it by hand:
+///
+/// ```
+/// use revolut_customer::{ApiError, ApiErrorExt, Client};
+///
+/// let client = Client::default();
+/// let error = client.sign_in("555-555-555", "9999").unwrap_err();
+///
+/// assert_eq!(error.as_api_error(), Some(&ApiError::InvalidPhone));
+/// ```
+pub trait ApiErrorExt {
+    /// Gets the API error, if the error is, or was caused by, an API error.
+    fn as_api_error(&self) -> Option<&ApiError>;
+}
+
+impl ApiErrorExt for Error {
+    fn as_api_error(&self) -> Option<&ApiError> {
+        self.downcast_ref::<ApiError>()
+    }
+}
+
+/// Parses the value of a `Retry-After` header.
+///
+/// If it's an HTTP date in the past, a zero duration will be returned.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        Some(Duration::from_secs(secs))
+    } else {
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        Some(
+            date.signed_duration_since(Utc::now())
+                .to_std()
+                .unwrap_or_default(),
+        )
+    }
 }
 
 /// Error response.
@@ -108,9 +275,61 @@ struct ErrResponse {
     pub(crate) code: Option<i32>,
 }
 
+impl ErrResponse {
+    /// Parses the error response from the body of an API response.
+    ///
+    /// If the `tracing` feature is enabled, the Revolut error code is logged.
+    fn from_response(response: &mut Response) -> Result<Self, Error> {
+        let err_response: Self = response.json().context(ApiError::ParseResponse)?;
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            status = %response.status(),
+            code = ?err_response.code,
+            "the API rejected the request"
+        );
+        Ok(err_response)
+    }
+}
+
+impl From<ErrResponse> for ApiError {
+    fn from(err_response: ErrResponse) -> Self {
+        ApiError::BadRequest {
+            message: err_response.message,
+            code: err_response.code,
+        }
+    }
+}
+
 /// Options for the client configuration.
-#[derive(Debug, Clone, Builder, Getters, Setters)]
-#[builder(setter(into), default)]
+///
+/// When using the `OptionsBuilder`, the client version must be a dotted version, such as `5.29`
+/// or `5.29.1`, and the API version must be an integer, or the build will fail.
+///
+/// The options can be serialized, to store the device configuration of an application and reuse
+/// it later. Missing fields take their default value when deserializing, and the values are
+/// validated in the same way as with the `OptionsBuilder`:
+///
+/// ```
+/// use revolut_customer::Options;
+///
+/// let options = Options::android().with_generated_device_id();
+/// let json = serde_json::to_string(&options).unwrap();
+///
+/// let loaded: Options = serde_json::from_str(&json).unwrap();
+/// assert_eq!(loaded.device_id(), options.device_id());
+///
+/// assert!(serde_json::from_str::<Options>(r#"{"api_version": "v1"}"#).is_err());
+/// ```
+#[derive(
+    Debug, Clone, PartialEq, Eq, Builder, Getters, CopyGetters, Setters, Serialize, Deserialize,
+)]
+#[builder(
+    setter(into),
+    default,
+    build_fn(validate = "Self::validate"),
+    derive(Deserialize)
+)]
+#[serde(try_from = "OptionsBuilder")]
 pub struct Options {
     /// Version of the client.
     #[get = "pub"]
@@ -127,6 +346,24 @@ pub struct Options {
     /// User agent of the device.
     #[get = "pub"]
     user_agent: String,
+    /// Locale of the user, such as `es-ES`.
+    ///
+    /// If set, it's sent in the `Accept-Language` header, so that the API returns the error
+    /// messages in the language of the user.
+    #[get = "pub"]
+    locale: Option<String>,
+    /// Timeout for the requests to the API.
+    ///
+    /// It applies to the whole request, from establishing the connection to reading the end of
+    /// the response, since the HTTP client has no separate timeout for reading the response. If
+    /// `None`, the default timeout of the HTTP client will be used.
+    #[get_copy = "pub"]
+    timeout: Option<Duration>,
+    /// Timeout for establishing the connections to the API.
+    ///
+    /// If `None`, only the general timeout will apply to the connection.
+    #[get_copy = "pub"]
+    connect_timeout: Option<Duration>,
 }
 
 impl Default for Options {
@@ -136,17 +373,218 @@ impl Default for Options {
             api_version: "1".to_owned(),
             device_id: "SOME-DEVICE-ID".to_owned(),
             device_model: "iPhone8,1".to_owned(),
-            user_agent: "Revolut/com.revolut.revolut (iPhone; iOS 11.1)".to_owned(),
+            user_agent: Self::build_user_agent(Platform::IPhone, "11.1"),
+            locale: None,
+            timeout: None,
+            connect_timeout: None,
         }
     }
 }
 
+impl TryFrom<OptionsBuilder> for Options {
+    type Error = String;
+
+    fn try_from(builder: OptionsBuilder) -> Result<Self, Self::Error> {
+        builder.build()
+    }
+}
+
+/// Platform of the device running the client.
+///
+/// Only the user agent of the iPhone application is known. Android will be added once the format
+/// of its user agent is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Platform {
+    /// iPhone, running iOS.
+    IPhone,
+}
+
+impl Platform {
+    /// Gets the name of the device, as sent in the user agent.
+    fn device_name(self) -> &'static str {
+        match self {
+            Platform::IPhone => "iPhone",
+        }
+    }
+
+    /// Gets the name of the operating system, as sent in the user agent.
+    fn os_name(self) -> &'static str {
+        match self {
+            Platform::IPhone => "iOS",
+        }
+    }
+}
+
+impl OptionsBuilder {
+    /// Validates the options set in the builder.
+    fn validate(&self) -> Result<(), String> {
+        if let Some(ref client_version) = self.client_version {
+            let mut parts = client_version.split('.');
+            let valid = parts.clone().count() >= 2
+                && parts.all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+            if !valid {
+                return Err(format!(
+                    "the client version {:?} is not a dotted version, such as \"5.29\"",
+                    client_version
+                ));
+            }
+        }
+        if let Some(ref api_version) = self.api_version {
+            if api_version.starts_with('+') || api_version.parse::<u32>().is_err() {
+                return Err(format!(
+                    "the API version {:?} is not an integer",
+                    api_version
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the version of the API from its number.
+    ///
+    /// This is equivalent to setting the API version to the string representation of the number.
+    pub fn api_version_num(&mut self, api_version: u32) -> &mut Self {
+        self.api_version = Some(api_version.to_string());
+        self
+    }
+}
+
 impl Options {
+    /// Gets the version of the API as a number.
+    ///
+    /// The API version is sent as a string in the `X-Api-Version` header, but it's always an
+    /// integer:
+    ///
+    /// ```
+    /// use revolut_customer::{Options, OptionsBuilder};
+    ///
+    /// assert_eq!(Options::default().api_version_num().unwrap(), 1);
+    ///
+    /// let options = OptionsBuilder::default().api_version_num(2).build().unwrap();
+    /// assert_eq!(options.api_version(), "2");
+    /// assert_eq!(options.api_version_num().unwrap(), 2);
+    /// ```
+    ///
+    /// It will only fail if the options contain an API version that is not an integer, which
+    /// can't happen if they were created with the `OptionsBuilder`.
+    pub fn api_version_num(&self) -> Result<u32, Error> {
+        Ok(self.api_version.parse()?)
+    }
+
+    /// Builds a user agent in the format used by the Revolut applications.
+    ///
+    /// The user agent contains the platform of the device with the version of its operating
+    /// system. The version of the application is not part of it, since it's sent in the
+    /// `X-Client-Version` header:
+    ///
+    /// ```
+    /// use revolut_customer::{Options, Platform};
+    ///
+    /// assert_eq!(
+    ///     Options::build_user_agent(Platform::IPhone, "11.1"),
+    ///     "Revolut/com.revolut.revolut (iPhone; iOS 11.1)"
+    /// );
+    /// ```
+    pub fn build_user_agent(platform: Platform, os_version: &str) -> String {
+        format!(
+            "Revolut/com.revolut.revolut ({}; {} {})",
+            platform.device_name(),
+            platform.os_name(),
+            os_version
+        )
+    }
+
     /// Gets the default iPhone options.
     pub fn iphone() -> Self {
         Self::default()
     }
 
+    /// Gets the iPhone options for the given client version, iOS version and device model.
+    ///
+    /// The user agent is generated from the iOS version, in the format used by the Revolut
+    /// iPhone application:
+    ///
+    /// ```
+    /// use revolut_customer::Options;
+    ///
+    /// let options = Options::iphone_with("5.30", "12.1", "iPhone10,6");
+    /// assert_eq!(options.client_version(), "5.30");
+    /// assert_eq!(options.device_model(), "iPhone10,6");
+    /// assert_eq!(
+    ///     options.user_agent(),
+    ///     "Revolut/com.revolut.revolut (iPhone; iOS 12.1)"
+    /// );
+    /// ```
+    pub fn iphone_with<C, I, M>(client_version: C, ios_version: I, device_model: M) -> Self
+    where
+        C: Into<String>,
+        I: AsRef<str>,
+        M: Into<String>,
+    {
+        Self {
+            client_version: client_version.into(),
+            device_model: device_model.into(),
+            user_agent: Self::build_user_agent(Platform::IPhone, ios_version.as_ref()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets a randomly generated device ID.
+    ///
+    /// The default device ID is the same for every client, while the Revolut applications use a
+    /// random UUID for each device. The generated ID will be different every time, so
+    /// [`with_persisted_device_id()`](#method.with_persisted_device_id) should be used to reuse
+    /// it across runs.
+    #[must_use]
+    pub fn with_generated_device_id(mut self) -> Self {
+        self.device_id = generate_device_id();
+        self
+    }
+
+    /// Sets the device ID stored in the given file, generating and storing a new one if the file
+    /// does not exist.
+    ///
+    /// This allows using the same device ID every time the application runs, as the Revolut
+    /// applications do:
+    ///
+    /// ```no_run
+    /// # use anyhow::Error;
+    /// use revolut_customer::Options;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let options = Options::default().with_persisted_device_id("device_id.txt")?;
+    /// println!("Device ID: {}", options.device_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_persisted_device_id<P>(mut self, path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let stored = match fs::read_to_string(path) {
+            Ok(device_id) => Some(device_id.trim().to_owned()).filter(|id| !id.is_empty()),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => {
+                return Err(Error::new(e).context(format!(
+                    "error reading the device ID from {}",
+                    path.display()
+                )))
+            }
+        };
+
+        self.device_id = if let Some(device_id) = stored {
+            device_id
+        } else {
+            let device_id = generate_device_id();
+            fs::write(path, &device_id)
+                .with_context(|| format!("error storing the device ID in {}", path.display()))?;
+            device_id
+        };
+        Ok(self)
+    }
+
     /// Gets the default Android options.
     pub fn android() -> Self {
         Self {
@@ -159,6 +597,35 @@ impl Options {
     }
 }
 
+/// Reads an environment variable, returning `None` if it's not set.
+#[cfg(feature = "env")]
+fn env_var(name: &'static str) -> Result<Option<String>, Error> {
+    match env::var(name) {
+        Ok(value) => Ok(Some(value)),
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(e) => Err(Error::new(e).context(ApiError::InvalidEnvVar { name })),
+    }
+}
+
+/// Reads a duration in milliseconds from an environment variable, returning `None` if it's not
+/// set.
+#[cfg(feature = "env")]
+fn env_millis(name: &'static str) -> Result<Option<Duration>, Error> {
+    env_var(name)?
+        .map(|value| {
+            value
+                .parse()
+                .map(Duration::from_millis)
+                .context(ApiError::InvalidEnvVar { name })
+        })
+        .transpose()
+}
+
+/// Generates a random device ID, in the format used by the Revolut iPhone application.
+fn generate_device_id() -> String {
+    Uuid::new_v4().to_hyphenated().to_string().to_uppercase()
+}
+
 /// API client.
 ///
 /// TODO: Client examples
@@ -192,6 +659,8 @@ impl Options {
 /// Accept: application/json
 /// ```
 ///
+/// If a locale is set in the options, it's also sent in the `Accept-Language` header.
+///
 /// For the authenticated APIs, it uses simple authentication with the User ID as the user and the
 /// access token as the password, adding the header:
 ///
@@ -200,41 +669,331 @@ impl Options {
 /// ```
 ///
 /// The last part is the Base64 encoding of the `{revolut-user-id}:{AccessToken}` pair.
+///
+/// Additional headers can be sent with every request by using
+/// [`set_extra_headers()`](#method.set_extra_headers). They take precedence over the headers
+/// generated from the options.
+///
+/// The client can be shared between threads in an `Arc` to call the API methods, but the
+/// authentication methods need a mutable reference. To share a logged in client and update its
+/// authentication from any thread, use a [`SharedClient`](shared/struct.SharedClient.html).
 #[derive(Debug, Clone)]
 pub struct Client {
     /// Options for the client.
     options: Options,
     /// HTTP client.
     client: reqwest::Client,
+    /// Whether the HTTP client was provided by the user.
+    http_client_provided: bool,
+    /// Transport used to send the requests, instead of the HTTP client.
+    transport: Option<Arc<dyn Transport>>,
+    /// Base URL of the API.
+    base_url: Url,
+    /// Extra headers to send with every request.
+    extra_headers: HeaderMap,
+    /// Proxy used to send the requests.
+    proxy: Option<Proxy>,
+    /// Whether invalid TLS certificates are accepted.
+    accept_invalid_certs: bool,
+    /// Whether the bodies of the requests are logged.
+    debug_bodies: bool,
+    /// Function receiving the logged bodies of the requests.
+    body_logger: Option<BodyLogger>,
     /// Client ID.
     user_id: Option<Uuid>,
     /// Access token.
     access_token: Option<String>,
+    /// Passcode sent to verify the sensitive operations.
+    verify_password: Option<HeaderValue>,
+    /// Idempotency key sent with the operations that move money or change the user data.
+    idempotency_key: Option<Uuid>,
+}
+
+/// Function receiving the logged bodies of the requests.
+#[derive(Clone)]
+struct BodyLogger(Arc<dyn Fn(&str) + Send + Sync>);
+
+impl fmt::Debug for BodyLogger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("BodyLogger")
+    }
 }
 
 impl Default for Client {
     fn default() -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: http_client_builder()
+                .build()
+                .expect("error building the HTTP client"),
+            http_client_provided: false,
+            transport: None,
             options: Options::default(),
+            base_url: BASE_API_URL.clone(),
+            extra_headers: HeaderMap::new(),
+            proxy: None,
+            accept_invalid_certs: false,
+            debug_bodies: false,
+            body_logger: None,
             user_id: None,
             access_token: None,
+            verify_password: None,
+            idempotency_key: None,
         }
     }
 }
 
 impl Client {
+    /// Creates a new client builder.
+    ///
+    /// This is equivalent to `ClientBuilder::default()`.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
     /// Creates a new client with the given options.
     pub fn with_options(options: Options) -> Self {
-        Self {
+        let mut client = Self {
             options,
             ..Self::default()
+        };
+        client.rebuild_http_client();
+        client
+    }
+
+    /// Creates a new client that sends all the requests through the given proxy.
+    ///
+    /// This is useful to capture the traffic with a debugging proxy such as
+    /// [mitmproxy](https://mitmproxy.org/). In that case, the certificate of the proxy will
+    /// probably need to be accepted by using
+    /// [`danger_accept_invalid_certs()`](#method.danger_accept_invalid_certs).
+    pub fn with_proxy(proxy: Proxy) -> Self {
+        let mut client = Self {
+            proxy: Some(proxy),
+            ..Self::default()
+        };
+        client.rebuild_http_client();
+        client
+    }
+
+    /// Creates a new client that uses the given HTTP client to perform the requests.
+    ///
+    /// This allows sharing the connection pool and the configuration of an HTTP client already
+    /// used in the application. The HTTP client will never be rebuilt, so the timeouts of the
+    /// options, the proxy and the certificate validation configuration will have no effect. The
+    /// compression of the responses will also depend on the configuration of the HTTP client.
+    pub fn with_http_client(http_client: reqwest::Client) -> Self {
+        Self {
+            client: http_client,
+            http_client_provided: true,
+            ..Self::default()
+        }
+    }
+
+    /// Creates an authenticated client from the environment variables.
+    ///
+    /// The `REVOLUT_USER_ID` and `REVOLUT_ACCESS_TOKEN` variables are required. The options of
+    /// the client are read from the following optional variables, and the default options are used
+    /// for the ones that are not set:
+    ///
+    /// - `REVOLUT_CLIENT_VERSION`, `REVOLUT_API_VERSION`, `REVOLUT_DEVICE_ID`,
+    ///   `REVOLUT_DEVICE_MODEL`, `REVOLUT_USER_AGENT` and `REVOLUT_LOCALE`.
+    /// - `REVOLUT_TIMEOUT_MS` and `REVOLUT_CONNECT_TIMEOUT_MS`, in milliseconds.
+    ///
+    /// If any of the required variables is not set, an `ApiError::MissingEnvVars` error listing
+    /// all the missing ones will be returned. This method is only available with the `env`
+    /// feature.
+    #[cfg(feature = "env")]
+    pub fn from_env() -> Result<Self, Error> {
+        let (user_id, access_token) = match (
+            env_var("REVOLUT_USER_ID")?,
+            env_var("REVOLUT_ACCESS_TOKEN")?,
+        ) {
+            (Some(user_id), Some(access_token)) => (user_id, access_token),
+            (user_id, access_token) => {
+                let mut vars = Vec::new();
+                if user_id.is_none() {
+                    vars.push("REVOLUT_USER_ID");
+                }
+                if access_token.is_none() {
+                    vars.push("REVOLUT_ACCESS_TOKEN");
+                }
+                return Err(ApiError::MissingEnvVars { vars }.into());
+            }
+        };
+
+        let mut builder = OptionsBuilder::default();
+        if let Some(client_version) = env_var("REVOLUT_CLIENT_VERSION")? {
+            let _ = builder.client_version(client_version);
+        }
+        if let Some(api_version) = env_var("REVOLUT_API_VERSION")? {
+            let _ = builder.api_version(api_version);
+        }
+        if let Some(device_id) = env_var("REVOLUT_DEVICE_ID")? {
+            let _ = builder.device_id(device_id);
+        }
+        if let Some(device_model) = env_var("REVOLUT_DEVICE_MODEL")? {
+            let _ = builder.device_model(device_model);
+        }
+        if let Some(user_agent) = env_var("REVOLUT_USER_AGENT")? {
+            let _ = builder.user_agent(user_agent);
+        }
+        if let Some(locale) = env_var("REVOLUT_LOCALE")? {
+            let _ = builder.locale(locale);
+        }
+        if let Some(timeout) = env_millis("REVOLUT_TIMEOUT_MS")? {
+            let _ = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = env_millis("REVOLUT_CONNECT_TIMEOUT_MS")? {
+            let _ = builder.connect_timeout(connect_timeout);
+        }
+        let options = builder.build().map_err(Error::msg)?;
+
+        let mut client = Self::with_options(options);
+        client.set_auth(user_id, access_token)?;
+        Ok(client)
+    }
+
+    /// Creates a new client that sends the requests through the given transport.
+    ///
+    /// The internal HTTP client is still used to build the requests, but they will be sent by
+    /// the transport. This is mostly useful to return scripted responses in tests, without
+    /// performing any network request.
+    pub fn with_transport<T>(transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        Self {
+            transport: Some(Arc::new(transport)),
+            ..Self::default()
         }
     }
 
+    /// Changes the transport used to send the requests.
+    ///
+    /// If `None`, the requests will be sent with the internal HTTP client.
+    pub fn set_transport(&mut self, transport: Option<Arc<dyn Transport>>) {
+        self.transport = transport;
+    }
+
     /// Changes the options of the client.
+    ///
+    /// The internal HTTP client will be rebuilt, so that the new timeouts apply to all further
+    /// requests, unless the HTTP client was provided with
+    /// [`with_http_client()`](#method.with_http_client).
     pub fn set_options(&mut self, options: Options) {
         self.options = options;
+        self.rebuild_http_client();
+    }
+
+    /// Changes the proxy used to send the requests.
+    ///
+    /// If `None`, no proxy will be used. The internal HTTP client will be rebuilt.
+    pub fn set_proxy(&mut self, proxy: Option<Proxy>) {
+        self.proxy = proxy;
+        self.rebuild_http_client();
+    }
+
+    /// Uses the proxy in the `HTTPS_PROXY` environment variable, if it's set.
+    ///
+    /// Proxies in the environment are not used unless this method is called. It returns whether
+    /// a proxy was found in the environment, and it will fail if the variable does not contain a
+    /// valid proxy URL.
+    pub fn set_proxy_from_env(&mut self) -> Result<bool, Error> {
+        if let Ok(proxy_url) = env::var("HTTPS_PROXY").or_else(|_| env::var("https_proxy")) {
+            let proxy = Proxy::https(&proxy_url).context(ApiError::InvalidProxy)?;
+            self.set_proxy(Some(proxy));
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Controls the validation of the TLS certificates.
+    ///
+    /// By default, invalid certificates are rejected. The internal HTTP client will be rebuilt.
+    ///
+    /// **Warning**: If invalid certificates are accepted, any certificate will be trusted, so
+    /// the traffic can be intercepted. This should only be used for debugging, for example with
+    /// [mitmproxy](https://mitmproxy.org/).
+    pub fn danger_accept_invalid_certs(&mut self, accept_invalid_certs: bool) {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self.rebuild_http_client();
+    }
+
+    /// Controls the logging of the JSON bodies of the requests sent to the API.
+    ///
+    /// This is useful to check what the client sends when the API changes. It's disabled by
+    /// default. The `password` and `code` fields of the bodies are redacted, and the headers,
+    /// including the `Authorization` header, are never logged. The bodies are logged with the
+    /// function set with [`set_body_logger()`](#method.set_body_logger), or at the debug level
+    /// using `tracing` if no function was set and the `tracing` feature is enabled.
+    pub fn set_debug_bodies(&mut self, debug_bodies: bool) {
+        self.debug_bodies = debug_bodies;
+    }
+
+    /// Sets the function receiving the request bodies logged when
+    /// [`set_debug_bodies()`](#method.set_debug_bodies) is enabled.
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use revolut_customer::Client;
+    ///
+    /// let bodies = Arc::new(Mutex::new(Vec::new()));
+    /// let mut client = Client::default();
+    /// client.set_debug_bodies(true);
+    /// client.set_body_logger({
+    ///     let bodies = Arc::clone(&bodies);
+    ///     move |body: &str| bodies.lock().unwrap().push(body.to_owned())
+    /// });
+    ///
+    /// // The phone is not valid, so no request will be sent.
+    /// assert!(client.sign_in("555-555-555", "9999").is_err());
+    /// assert!(bodies.lock().unwrap().is_empty());
+    /// ```
+    pub fn set_body_logger<F>(&mut self, logger: F)
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.body_logger = Some(BodyLogger(Arc::new(logger)));
+    }
+
+    /// Changes the base URL of the API.
+    ///
+    /// This is useful to point the client to a different server, such as a proxy or a mock
+    /// server for testing. By default, `https://api.revolut.com/` is used.
+    ///
+    /// A trailing slash is added to the path of the URL if it does not have one, so that the
+    /// endpoints are appended to the full path, and not to its parent. URLs that can't have a
+    /// path, such as `mailto:` URLs, are rejected with an `ApiError::InvalidBaseUrl` error.
+    pub fn set_base_url(&mut self, mut base_url: Url) -> Result<(), Error> {
+        if base_url.cannot_be_a_base() {
+            return Err(ApiError::InvalidBaseUrl.into());
+        }
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+        self.base_url = base_url;
+        Ok(())
+    }
+
+    /// Gets the base URL of the API.
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Sets extra headers to send with every request to the API.
+    ///
+    /// This allows sending headers that are not modeled by the client options. The extra headers
+    /// will override the headers generated from the options if their names collide.
+    pub fn set_extra_headers(&mut self, extra_headers: HeaderMap) {
+        self.extra_headers = extra_headers;
+    }
+
+    /// Gets the extra headers sent with every request to the API.
+    pub fn extra_headers(&self) -> &HeaderMap {
+        &self.extra_headers
     }
 
     /// Sets the user authentication information for the client.
@@ -263,38 +1022,593 @@ impl Client {
         self.access_token.as_ref()
     }
 
+    /// Checks whether the client has the authentication information.
+    ///
+    /// Methods requiring authentication will fail with `ApiError::NotLoggedIn` if this returns
+    /// `false`.
+    pub fn is_authenticated(&self) -> bool {
+        self.user_id.is_some() && self.access_token.is_some()
+    }
+
+    /// Gets the user ID and the access token used to authenticate the requests.
+    ///
+    /// It returns `ApiError::NotLoggedIn` if the client does not have the authentication
+    /// information.
+    fn auth_pair(&self) -> Result<(&Uuid, &str), Error> {
+        if let (Some(user_id), Some(access_token)) = (&self.user_id, &self.access_token) {
+            Ok((user_id, access_token))
+        } else {
+            Err(ApiError::NotLoggedIn.into())
+        }
+    }
+
     /// Removes the user authentication information.
     ///
-    /// This is effectively logging the user out.
+    /// This is effectively logging the user out. The passcode set with
+    /// [`set_verify_password()`](#method.set_verify_password) is removed too.
     pub fn unset_auth(&mut self) {
         self.user_id = None;
         self.access_token = None;
+        self.verify_password = None;
     }
 
-    /// Sets the headers with the provided documentation.
-    fn set_headers(&self, mut request_builder: RequestBuilder) -> RequestBuilder {
-        if !self.options.client_version.is_empty() {
-            request_builder =
-                request_builder.header("X-Client-Version", self.options.client_version.as_str());
-        }
-        if !self.options.api_version.is_empty() {
-            request_builder =
-                request_builder.header("X-Api-Version", self.options.api_version.as_str());
-        }
-        if !self.options.device_id.is_empty() {
-            request_builder =
-                request_builder.header("X-Device-Id", self.options.device_id.as_str());
-        }
-        if !self.options.device_model.is_empty() {
-            request_builder =
-                request_builder.header("X-Device-Model", self.options.device_id.as_str());
-        }
-        if !self.options.user_agent.is_empty() {
-            request_builder = request_builder.header(
-                reqwest::header::USER_AGENT,
-                self.options.user_agent.as_str(),
+    /// Sets the passcode used to verify the sensitive operations.
+    ///
+    /// Some operations, such as changing the email address of the user or revoking the session of
+    /// a device, require the user to verify their identity again. The mobile application does
+    /// this by sending the passcode of the user in the `X-Verify-Password` header of the request.
+    /// The passcode is sent as is, without any hashing, so the header is marked as sensitive, and
+    /// it's only attached to the following requests:
+    ///
+    /// - [`change_current_user_email()`](#method.change_current_user_email)
+    /// - [`revoke_device()`](#method.revoke_device)
+    ///
+    /// Revolut does not document which operations require the verification, so this list might
+    /// be incomplete. Other headers can be sent with
+    /// [`set_extra_headers()`](#method.set_extra_headers).
+    ///
+    /// The passcode must only contain ASCII digits, or `ApiError::InvalidPasscode` will be
+    /// returned.
+    pub fn set_verify_password<P>(&mut self, passcode: P) -> Result<(), Error>
+    where
+        P: AsRef<str>,
+    {
+        let passcode = passcode.as_ref();
+        if passcode.is_empty() || !passcode.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ApiError::InvalidPasscode.into());
+        }
+
+        let mut value = HeaderValue::from_str(passcode).context(ApiError::InvalidPasscode)?;
+        value.set_sensitive(true);
+        self.verify_password = Some(value);
+        Ok(())
+    }
+
+    /// Removes the passcode used to verify the sensitive operations.
+    pub fn unset_verify_password(&mut self) {
+        self.verify_password = None;
+    }
+
+    /// Creates a copy of the client that sends the given idempotency key.
+    ///
+    /// The operations that move money or change the user data send an `Idempotency-Key` header,
+    /// so that the API can recognize a retried request and avoid performing the operation twice.
+    /// These operations are the following:
+    ///
+    /// - [`top_up()`](#method.top_up)
+    /// - [`exchange()`](#method.exchange)
+    /// - [`withdraw()`](#method.withdraw)
+    /// - [`change_current_user_address()`](#method.change_current_user_address)
+    /// - [`patch_current_user_address()`](#method.patch_current_user_address)
+    /// - [`change_current_user_email()`](#method.change_current_user_email)
+    /// - [`close_pocket()`](#method.close_pocket)
+    /// - [`open_pocket()`](#method.open_pocket)
+    /// - [`confirm_card()`](#method.confirm_card)
+    /// - [`verify_card()`](#method.verify_card)
+    /// - [`set_auto_topup()`](#method.set_auto_topup)
+    /// - [`revoke_device()`](#method.revoke_device)
+    ///
+    /// By default, a new random key is generated for each request, so retrying an operation would
+    /// perform it again. To retry an operation safely, it must be called on a client with a fixed
+    /// key, and the key must not be used for any other operation:
+    ///
+    /// ```no_run
+    /// # use anyhow::Error;
+    /// use revolut_customer::{Amount, Client};
+    /// use uuid::Uuid;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let mut client = Client::default();
+    /// client.set_auth("b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d", "some-access-token")?;
+    ///
+    /// let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d".parse()?;
+    /// let top_up_client = client.with_idempotency_key(Uuid::new_v4());
+    /// let transaction = top_up_client
+    ///     .top_up(card_id, Amount::from_repr(50_00), "EUR")
+    ///     .or_else(|_| top_up_client.top_up(card_id, Amount::from_repr(50_00), "EUR"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Revolut does not document whether the API honors the idempotency keys, so this is a
+    /// safeguard, not a guarantee.
+    #[must_use]
+    pub fn with_idempotency_key(&self, idempotency_key: Uuid) -> Self {
+        Self {
+            idempotency_key: Some(idempotency_key),
+            ..self.clone()
+        }
+    }
+
+    /// Gets the full URL of the given endpoint.
+    ///
+    /// Leading slashes of the path are ignored, so that the endpoint is always relative to the
+    /// base URL.
+    fn endpoint(&self, path: &str) -> Url {
+        self.base_url
+            .join(path.trim_start_matches('/'))
+            .expect("error joining the endpoint to the base API URL")
+    }
+
+    /// Gets the headers that will be sent with every request to the API.
+    ///
+    /// These are the headers generated from the client options (empty options are not sent,
+    /// neither are options that are not valid header values), merged with the extra headers of
+    /// the client. If an extra header has the same name as one of the headers generated from the
+    /// options, the extra header takes precedence.
+    pub fn headers(&self) -> HeaderMap {
+        let options_headers = [
+            (
+                HeaderName::from_static("x-client-version"),
+                &self.options.client_version,
+            ),
+            (
+                HeaderName::from_static("x-api-version"),
+                &self.options.api_version,
+            ),
+            (
+                HeaderName::from_static("x-device-id"),
+                &self.options.device_id,
+            ),
+            (
+                HeaderName::from_static("x-device-model"),
+                &self.options.device_model,
+            ),
+            (USER_AGENT, &self.options.user_agent),
+        ];
+
+        let mut headers = HeaderMap::new();
+        let locale_header = self
+            .options
+            .locale
+            .as_ref()
+            .map(|locale| (ACCEPT_LANGUAGE, locale));
+        for (name, value) in options_headers.iter().cloned().chain(locale_header) {
+            if !value.is_empty() {
+                if let Ok(value) = HeaderValue::from_str(value) {
+                    let _ = headers.insert(name, value);
+                }
+            }
+        }
+
+        for name in self.extra_headers.keys() {
+            let _ = headers.remove(name);
+            for value in self.extra_headers.get_all(name) {
+                let _ = headers.append(name.clone(), value.clone());
+            }
+        }
+
+        headers
+    }
+
+    /// Rebuilds the internal HTTP client with the current configuration.
+    ///
+    /// It does nothing if the HTTP client was provided by the user.
+    fn rebuild_http_client(&mut self) {
+        if self.http_client_provided {
+            return;
+        }
+
+        let mut builder = http_client_builder();
+        if let Some(timeout) = self.options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.options.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(ref proxy) = self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+        {
+            if self.accept_invalid_certs {
+                builder = builder.danger_accept_invalid_certs(true);
+            }
+        }
+        self.client = builder.build().expect("error building the HTTP client");
+    }
+
+    /// Sends a request to the API.
+    ///
+    /// If the `tracing` feature is enabled, the method and the path of the request, and the
+    /// status code of the response are logged. Neither the headers nor the bodies of the requests
+    /// are logged, so that no credentials end up in the logs.
+    fn send(&self, request_builder: RequestBuilder) -> Result<Response, Error> {
+        let request = request_builder.build().context(ApiError::RequestFailure)?;
+
+        #[cfg(feature = "tracing")]
+        let (method, path) = (request.method().clone(), request.url().path().to_owned());
+        #[cfg(feature = "tracing")]
+        tracing::debug!(%method, %path, "sending request");
+
+        let response = if let Some(ref transport) = self.transport {
+            transport.execute(request)
+        } else {
+            self.client.execute(request).map_err(Error::from)
+        };
+
+        #[cfg(feature = "tracing")]
+        match response {
+            Ok(ref response) if response.status().is_success() => {
+                tracing::debug!(%method, %path, status = %response.status(), "received response");
+            }
+            Ok(ref response) => {
+                tracing::warn!(%method, %path, status = %response.status(), "received error response");
+            }
+            Err(ref e) => tracing::warn!(%method, %path, error = %e, "request failed"),
+        }
+
+        response.context(ApiError::RequestFailure)
+    }
+
+    /// Sets the given data as the JSON body of the request.
+    ///
+    /// If the logging of the bodies is enabled, the body is logged with the sensitive fields
+    /// redacted. Otherwise, this is equivalent to `RequestBuilder::json()`.
+    fn json_body<T>(&self, request_builder: RequestBuilder, body: &T) -> RequestBuilder
+    where
+        T: Serialize + ?Sized,
+    {
+        if self.debug_bodies {
+            let redacted = serde_json::to_value(body).map_or_else(
+                |_| "<the body could not be serialized>".to_owned(),
+                |mut value| {
+                    redact_body(&mut value);
+                    value.to_string()
+                },
             );
+
+            if let Some(BodyLogger(ref logger)) = self.body_logger {
+                logger(&redacted);
+            } else {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(body = %redacted, "request body");
+            }
         }
-        request_builder
+
+        request_builder.json(body)
+    }
+
+    /// Sets the headers with the provided documentation.
+    fn set_headers(&self, request_builder: RequestBuilder) -> RequestBuilder {
+        request_builder.headers(self.headers())
+    }
+
+    /// Sets the `Idempotency-Key` header, with the idempotency key of the client or a new random
+    /// key.
+    fn set_idempotency_key_header(&self, request_builder: RequestBuilder) -> RequestBuilder {
+        let idempotency_key = self.idempotency_key.unwrap_or_else(Uuid::new_v4);
+        request_builder.header(
+            HeaderName::from_static("idempotency-key"),
+            idempotency_key.to_string(),
+        )
+    }
+
+    /// Sets the `X-Verify-Password` header, if the client has a passcode to verify the sensitive
+    /// operations.
+    fn set_verify_password_header(&self, request_builder: RequestBuilder) -> RequestBuilder {
+        if let Some(ref passcode) = self.verify_password {
+            request_builder.header(
+                HeaderName::from_static("x-verify-password"),
+                passcode.clone(),
+            )
+        } else {
+            request_builder
+        }
+    }
+}
+
+/// Redacts the sensitive fields of a request body, at any depth.
+fn redact_body(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if key == "password" || key == "code" {
+                    *value = serde_json::Value::String("<redacted>".to_owned());
+                } else {
+                    redact_body(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_body),
+        _ => {}
+    }
+}
+
+/// Reads the body of a successful API response, returning `None` if it's empty.
+///
+/// The API could answer with an empty body, such as the one of a `204 No Content` response, even
+/// in the endpoints that usually return data.
+fn read_body(response: &mut Response) -> Result<Option<String>, Error> {
+    let body = response.text().context(ApiError::ParseResponse)?;
+    if body.trim().is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(body))
+    }
+}
+
+/// Gets the error for an unsuccessful API response.
+///
+/// A `400 Bad Request` response is parsed as an `ApiError::BadRequest`, with the message and
+/// code of the API, and the unexpected status codes result in an `ApiError::Other`, with the
+/// body of the response.
+fn handle_error_status(response: &mut Response) -> Error {
+    match response.status() {
+        StatusCode::UNAUTHORIZED => ApiError::unauthorized(response).into(),
+        StatusCode::BAD_REQUEST => match ErrResponse::from_response(response) {
+            Ok(err_response) => ApiError::from(err_response).into(),
+            Err(e) => e,
+        },
+        StatusCode::NOT_FOUND => ApiError::NotFound.into(),
+        StatusCode::TOO_MANY_REQUESTS => ApiError::rate_limited(response).into(),
+        status_code => ApiError::Other {
+            status_code,
+            body: response.text().ok(),
+        }
+        .into(),
+    }
+}
+
+/// Parses the JSON body of a successful API response.
+///
+/// An empty body results in an `ApiError::EmptyResponse` error, instead of a parsing error.
+fn parse_json<T>(response: &mut Response) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    let body = read_body(response)?.ok_or(ApiError::EmptyResponse)?;
+    serde_json::from_str(&body).context(ApiError::ParseResponse)
+}
+
+/// Parses the JSON array in the body of a successful API response.
+///
+/// An empty body is considered an empty list.
+fn parse_json_list<T>(response: &mut Response) -> Result<Vec<T>, Error>
+where
+    T: DeserializeOwned,
+{
+    read_body(response)?.map_or_else(
+        || Ok(Vec::new()),
+        |body| serde_json::from_str(&body).context(ApiError::ParseResponse),
+    )
+}
+
+/// Creates a builder for the internal HTTP client, with the TLS backend selected by the features.
+///
+/// If both the `rustls-tls` and the `native-tls` features are enabled, the native TLS backend is
+/// used, since `rustls-tls` is enabled by default and could not be disabled otherwise. The gzip
+/// decompression of the responses is enabled explicitly, so that it does not depend on the
+/// defaults of the HTTP client.
+fn http_client_builder() -> reqwest::ClientBuilder {
+    #[cfg_attr(
+        not(any(feature = "native-tls", feature = "rustls-tls")),
+        allow(unused_mut)
+    )]
+    let mut builder = reqwest::Client::builder().gzip(true);
+    #[cfg(feature = "native-tls")]
+    {
+        builder = builder.use_default_tls();
+    }
+    #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+    {
+        builder = builder.use_rustls_tls();
+    }
+    builder
+}
+
+/// Builder for the API client.
+///
+/// It allows setting the options, the base URL and the authentication information of the client
+/// in one go:
+///
+/// ```
+/// # use anyhow::Error;
+/// use std::time::Duration;
+///
+/// use revolut_customer::{Client, Options};
+///
+/// # fn main() -> Result<(), Error> {
+/// let client = Client::builder()
+///     .options(Options::android())
+///     .timeout(Duration::from_secs(10))
+///     .user_id("b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d")
+///     .access_token("some-access-token")
+///     .build()?;
+///
+/// assert!(client.user_id().is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    /// Options for the client.
+    options: Option<Options>,
+    /// Base URL of the API.
+    base_url: Option<Url>,
+    /// Extra headers to send with every request.
+    extra_headers: Option<HeaderMap>,
+    /// HTTP client provided by the user.
+    http_client: Option<reqwest::Client>,
+    /// Proxy used to send the requests.
+    proxy: Option<Proxy>,
+    /// Whether invalid TLS certificates are accepted.
+    accept_invalid_certs: bool,
+    /// Timeout for the requests to the API.
+    timeout: Option<Duration>,
+    /// Timeout for establishing the connections to the API.
+    connect_timeout: Option<Duration>,
+    /// Client ID.
+    user_id: Option<String>,
+    /// Access token.
+    access_token: Option<String>,
+}
+
+impl ClientBuilder {
+    /// Sets the options of the client.
+    ///
+    /// If not set, the default options will be used.
+    pub fn options<O>(&mut self, options: O) -> &mut Self
+    where
+        O: Into<Options>,
+    {
+        self.options = Some(options.into());
+        self
+    }
+
+    /// Sets the base URL of the API.
+    ///
+    /// If not set, `https://api.revolut.com/` will be used. Building the client will fail if the
+    /// URL can't be a base URL, see
+    /// [`Client::set_base_url()`](struct.Client.html#method.set_base_url).
+    pub fn base_url<U>(&mut self, base_url: U) -> &mut Self
+    where
+        U: Into<Url>,
+    {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets extra headers to send with every request to the API.
+    ///
+    /// They will override the headers generated from the options if their names collide.
+    pub fn extra_headers(&mut self, extra_headers: HeaderMap) -> &mut Self {
+        self.extra_headers = Some(extra_headers);
+        self
+    }
+
+    /// Sets the HTTP client used to perform the requests.
+    ///
+    /// If set, the timeouts, the proxy and the certificate validation configuration will have no
+    /// effect. See [`Client::with_http_client()`](struct.Client.html#method.with_http_client) for
+    /// more information.
+    pub fn http_client(&mut self, http_client: reqwest::Client) -> &mut Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the proxy used to send the requests.
+    pub fn proxy(&mut self, proxy: Proxy) -> &mut Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Controls the validation of the TLS certificates.
+    ///
+    /// See [`Client::danger_accept_invalid_certs()`](struct.Client.html#method.danger_accept_invalid_certs)
+    /// for more information.
+    pub fn danger_accept_invalid_certs(&mut self, accept_invalid_certs: bool) -> &mut Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Sets the timeout for the requests to the API.
+    ///
+    /// It overrides the timeout of the options.
+    pub fn timeout<D>(&mut self, timeout: D) -> &mut Self
+    where
+        D: Into<Duration>,
+    {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    /// Sets the timeout for establishing the connections to the API.
+    ///
+    /// It overrides the connection timeout of the options.
+    pub fn connect_timeout<D>(&mut self, connect_timeout: D) -> &mut Self
+    where
+        D: Into<Duration>,
+    {
+        self.connect_timeout = Some(connect_timeout.into());
+        self
+    }
+
+    /// Sets the user ID for the authentication.
+    ///
+    /// The access token must also be set.
+    pub fn user_id<I>(&mut self, user_id: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// Sets the access token for the authentication.
+    ///
+    /// The user ID must also be set.
+    pub fn access_token<T>(&mut self, access_token: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.access_token = Some(access_token.into());
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// It will fail if only one of the user ID and the access token was set, or if the user ID
+    /// is not a valid UUID.
+    pub fn build(&self) -> Result<Client, Error> {
+        let mut options = self.options.clone().unwrap_or_default();
+        if self.timeout.is_some() {
+            options.timeout = self.timeout;
+        }
+        if self.connect_timeout.is_some() {
+            options.connect_timeout = self.connect_timeout;
+        }
+
+        let mut client = if let Some(ref http_client) = self.http_client {
+            Client {
+                options,
+                ..Client::with_http_client(http_client.clone())
+            }
+        } else {
+            let mut client = Client {
+                options,
+                proxy: self.proxy.clone(),
+                accept_invalid_certs: self.accept_invalid_certs,
+                ..Client::default()
+            };
+            client.rebuild_http_client();
+            client
+        };
+        if let Some(ref base_url) = self.base_url {
+            client.set_base_url(base_url.clone())?;
+        }
+        if let Some(ref extra_headers) = self.extra_headers {
+            client.set_extra_headers(extra_headers.clone());
+        }
+
+        match (&self.user_id, &self.access_token) {
+            (Some(user_id), Some(access_token)) => {
+                client.set_auth(user_id, access_token.clone())?;
+            }
+            (None, None) => {}
+            _ => return Err(ApiError::IncompleteAuth.into()),
+        }
+
+        Ok(client)
     }
 }
@@ -0,0 +1,162 @@
+//! Revolut money representation
+//!
+//! This module holds the `Money` type, an amount in a given currency, and the `MoneyLocale`
+//! configuration used to render it for different locales:
+//!
+//! ```
+//! use revolut_customer::{money::{MoneyLocale, SymbolPosition}, Amount, Money};
+//!
+//! let money = Money::new(Amount::from_repr(1_234_56), "EUR");
+//! assert_eq!(format!("{}", money), "1234.56 EUR");
+//!
+//! let german = MoneyLocale::new(',', '.', " €", SymbolPosition::After);
+//! assert_eq!(money.format_locale(&german), "1.234,56 €");
+//! ```
+
+use std::{cmp::Ordering, fmt};
+
+use getset::{CopyGetters, Getters};
+
+use crate::amount::Amount;
+
+/// Money representation.
+///
+/// It holds an amount together with the currency it's expressed in. Money in different
+/// currencies can't be added nor compared: [`checked_add()`](#method.checked_add) will return
+/// `None`, and so will `partial_cmp()`, which makes all comparison operators return `false`.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct Money {
+    /// Amount of money.
+    #[get_copy = "pub"]
+    amount: Amount,
+    /// Currency of the money.
+    #[get = "pub"]
+    currency: String, // TODO: enum
+}
+
+impl Money {
+    /// Creates a new money representation.
+    pub fn new<C>(amount: Amount, currency: C) -> Self
+    where
+        C: Into<String>,
+    {
+        Self {
+            amount,
+            currency: currency.into(),
+        }
+    }
+
+    /// Checks whether the money is expressed in the same currency as the given money.
+    pub fn same_currency(&self, other: &Self) -> bool {
+        self.currency == other.currency
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if the currencies are different
+    /// or if overflow occurred.
+    ///
+    /// ```
+    /// use revolut_customer::{Amount, Money};
+    ///
+    /// let eur = Money::new(Amount::from_repr(10_50), "EUR");
+    /// let total = eur.checked_add(&Money::new(Amount::from_repr(2_25), "EUR"));
+    /// assert_eq!(total, Some(Money::new(Amount::from_repr(12_75), "EUR")));
+    ///
+    /// assert!(eur.checked_add(&Money::new(Amount::from_repr(2_25), "GBP")).is_none());
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        if self.same_currency(rhs) {
+            self.amount
+                .checked_add(rhs.amount)
+                .map(|amount| Self::new(amount, self.currency.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Formats the money with the given locale.
+    ///
+    /// The amount is always rendered with two decimal digits, and the integer part is grouped
+    /// in thousands:
+    ///
+    /// ```
+    /// use revolut_customer::{money::{MoneyLocale, SymbolPosition}, Amount, Money};
+    ///
+    /// let money = Money::new(Amount::from_repr(1_234_56), "USD");
+    /// let us = MoneyLocale::new('.', ',', "$", SymbolPosition::Before);
+    /// assert_eq!(money.format_locale(&us), "$1,234.56");
+    /// ```
+    pub fn format_locale(&self, locale: &MoneyLocale) -> String {
+        let number = self
+            .amount
+            .format_grouped(locale.grouping_separator, locale.decimal_separator);
+
+        match locale.symbol_position {
+            SymbolPosition::Before => format!("{}{}", locale.symbol, number),
+            SymbolPosition::After => format!("{}{}", number, locale.symbol),
+        }
+    }
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.same_currency(other) {
+            Some(self.amount.cmp(&other.amount))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+/// Locale configuration to format money.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct MoneyLocale {
+    /// Character separating the integer part from the decimal part.
+    #[get_copy = "pub"]
+    decimal_separator: char,
+    /// Character separating each group of thousands.
+    #[get_copy = "pub"]
+    grouping_separator: char,
+    /// Currency symbol.
+    ///
+    /// It's placed right next to the number, so it should contain any needed whitespace.
+    #[get = "pub"]
+    symbol: String,
+    /// Position of the currency symbol.
+    #[get_copy = "pub"]
+    symbol_position: SymbolPosition,
+}
+
+impl MoneyLocale {
+    /// Creates a new money locale configuration.
+    pub fn new<S>(
+        decimal_separator: char,
+        grouping_separator: char,
+        symbol: S,
+        symbol_position: SymbolPosition,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            decimal_separator,
+            grouping_separator,
+            symbol: symbol.into(),
+            symbol_position,
+        }
+    }
+}
+
+/// Position of the currency symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolPosition {
+    /// The symbol goes before the number.
+    Before,
+    /// The symbol goes after the number.
+    After,
+}
@@ -0,0 +1,114 @@
+//! Phone number representation
+//!
+//! This module holds the `Phone` type and its `ParseError`. Phone numbers are expected in the
+//! [E.164](https://en.wikipedia.org/wiki/E.164) format, which is the one used by the Revolut API:
+//!
+//! ```
+//! use revolut_customer::Phone;
+//!
+//! let phone = "+1555555555".parse::<Phone>().unwrap();
+//! assert_eq!(format!("{}", phone), "+1555555555");
+//!
+//! assert!("1555555555".parse::<Phone>().is_err());
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use anyhow::Error;
+use serde::{Deserialize, Deserializer};
+
+/// Minimum number of digits of an E.164 phone number.
+const MIN_DIGITS: usize = 7;
+/// Maximum number of digits of an E.164 phone number.
+const MAX_DIGITS: usize = 15;
+
+/// Phone number.
+///
+/// Phone numbers created by parsing a string are always valid E.164 phone numbers: a `+` sign
+/// followed by 7 to 15 digits, the first of them not being a zero. This is the format expected by
+/// the sign-in endpoints, so [`Client::sign_in()`](../struct.Client.html#method.sign_in) parses the
+/// phone before performing any request.
+///
+/// The phone of a [`User`](../private/struct.User.html) is read as sent by the API, even if it's
+/// not in that format, since losing the whole user because of an unexpected phone format would be
+/// worse. Such phones should be checked with [`is_valid()`](#method.is_valid) before using them
+/// to sign in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Phone {
+    /// The phone number.
+    number: String,
+    /// Whether the phone number is a valid E.164 phone number.
+    valid: bool,
+}
+
+impl Phone {
+    /// Gets the phone number as a string.
+    pub fn as_str(&self) -> &str {
+        &self.number
+    }
+
+    /// Checks whether the phone number is a valid E.164 phone number.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+}
+
+/// Checks if the given phone number is a valid E.164 phone number.
+fn is_e164(number: &str) -> bool {
+    if let Some(digits) = number.strip_prefix('+') {
+        digits.len() >= MIN_DIGITS
+            && digits.len() <= MAX_DIGITS
+            && !digits.starts_with('0')
+            && digits.chars().all(|c| c.is_ascii_digit())
+    } else {
+        false
+    }
+}
+
+impl FromStr for Phone {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if is_e164(s) {
+            Ok(Self {
+                number: s.to_owned(),
+                valid: true,
+            })
+        } else {
+            Err(ParseError {
+                phone_str: s.to_owned(),
+            }
+            .into())
+        }
+    }
+}
+
+impl AsRef<str> for Phone {
+    fn as_ref(&self) -> &str {
+        &self.number
+    }
+}
+
+impl fmt::Display for Phone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number)
+    }
+}
+
+impl<'de> Deserialize<'de> for Phone {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = String::deserialize(de)?;
+        let valid = is_e164(&number);
+        Ok(Self { number, valid })
+    }
+}
+
+/// Phone number parsing error.
+#[derive(Debug, Clone, thiserror::Error, PartialEq)]
+#[error("the phone number {phone_str} is not a valid E.164 phone number")]
+pub struct ParseError {
+    pub(crate) phone_str: String,
+}
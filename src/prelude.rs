@@ -0,0 +1,16 @@
+//! Prelude
+//!
+//! This module re-exports the most commonly used types of the crate, so that they can be imported
+//! at once:
+//!
+//! ```
+//! use revolut_customer::prelude::*;
+//!
+//! let client = Client::default();
+//! assert!(!client.is_authenticated());
+//! ```
+
+pub use crate::{
+    private::{Address, Card, User, Wallet},
+    Amount, ApiError, ApiErrorExt, Client, Options, OptionsBuilder,
+};
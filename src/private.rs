@@ -1,17 +1,82 @@
 //! Private methods of the client.
+//!
+//! The methods in this module require the client to have the authentication information, with the
+//! exception of the sign-in methods, that obtain it. Endpoints that never require authentication
+//! are in the [`public`](../public/index.html) module.
 
-use chrono::{DateTime, NaiveDate, Utc};
+use std::fmt;
+
+use anyhow::{Context, Error};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use derive_builder::Builder;
 use getset::{CopyGetters, Getters, Setters};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
-use crate::amount::Amount;
+use crate::{amount::Amount, country::Country, email::Email, money::Money, phone::Phone, ApiError};
+
+/// Implements the conversions of an enumeration of values sent by the API.
+///
+/// The enumeration must have an `Unknown(String)` variant, that will hold the values not known by
+/// this crate. Values are matched case-insensitively.
+macro_rules! impl_api_enum {
+    ($name:ident { $($variant:ident => $value:expr,)* }) => {
+        impl $name {
+            /// Gets the string representation of the value, as sent by the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value.to_uppercase().as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value.to_owned()),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(de: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let value = String::deserialize(de)?;
+                Ok(Self::from(value.as_str()))
+            }
+        }
+    };
+}
 
 mod auth;
+mod devices;
 mod exchange;
+mod statements;
 mod transactions;
 mod user;
 
+pub use self::{
+    auth::{ConfirmResult, VerificationChallenge, VerificationType},
+    devices::Device,
+    exchange::ExchangeQuote,
+    statements::StatementFormat,
+    transactions::{Counterparty, Merchant, Transaction, TransactionsIter},
+    user::{
+        AutoTopup, AutoTopupStatus, Card, CardBrand, CardType, CardTypeParseError, CurrencyLimit,
+        ExpiryDate, Issuer, Limits, UserFull,
+    },
+};
+
 /// User information structure.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
 #[serde(rename_all = "camelCase")]
@@ -21,7 +86,7 @@ pub struct User {
     id: Uuid,
     /// User creation date.
     #[get_copy = "pub"]
-    #[serde(with = "chrono::serde::ts_milliseconds")]
+    #[serde(deserialize_with = "deserialize_timestamp")]
     created_date: DateTime<Utc>,
     /// Address of the user.
     #[get = "pub"]
@@ -38,39 +103,115 @@ pub struct User {
     last_name: String,
     /// Phone of the user.
     #[get = "pub"]
-    phone: String, // TODO: properly parse
+    phone: Phone,
     /// Email of the user.
     #[get = "pub"]
-    email: String, // TODO: struct Email
+    email: Email,
     /// Wether the email is verified
     #[get_copy = "pub"]
     email_verified: bool,
     /// State of the user.
     #[get = "pub"]
-    state: String, // TODO: enum
+    state: UserState,
     /// Referral code.
     #[get = "pub"]
     referral_code: String,
     /// Unknown.
+    ///
+    /// It will be `None` if the API does not send it.
     #[get = "pub"]
-    kyc: String,
+    kyc: Option<String>,
     /// Accepted terms and conditions version.
     #[get = "pub"]
     terms_version: String,
     /// Wether the user is under review.
+    ///
+    /// It will be `false` if the API does not send it.
     #[get_copy = "pub"]
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     under_review: bool,
     /// Wether the user risk has been assessed (unknown meaning.)
+    ///
+    /// It will be `false` if the API does not send it.
     #[get_copy = "pub"]
+    #[serde(default, deserialize_with = "deserialize_null_default")]
     risk_assessed: bool,
     /// Locale of the user.
     #[get = "pub"]
     locale: String, // TODO: enum
     /// Unknown "sof" structure.
+    ///
+    /// It will be `None` if the API does not send it.
     #[get = "pub"]
-    sof: Sof,
+    sof: Option<Sof>,
 }
 
+impl User {
+    /// Parses the user from its JSON representation, as returned by the API.
+    ///
+    /// This allows parsing captured API responses without a client.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).context(ApiError::ParseResponse)
+    }
+
+    /// Parses the user and the wallet from the JSON response of the current user endpoint.
+    ///
+    /// See [`Client::current_user()`](../struct.Client.html#method.current_user).
+    pub fn from_current_user_json(json: &str) -> Result<(Self, Wallet), Error> {
+        /// Response of the current user endpoint.
+        #[derive(Debug, Deserialize)]
+        struct CurrentUserResponse {
+            /// User information.
+            user: User,
+            /// Wallet information.
+            wallet: Wallet,
+        }
+
+        let response: CurrentUserResponse =
+            serde_json::from_str(json).context(ApiError::ParseResponse)?;
+        Ok((response.user, response.wallet))
+    }
+
+    /// Checks whether the account of the user can be used.
+    ///
+    /// The account can be used if it's active and it's not under review.
+    pub fn is_usable(&self) -> bool {
+        self.state == UserState::Active && !self.under_review
+    }
+}
+
+impl fmt::Display for User {
+    /// Formats the user as `First Last <email>`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} <{}>", self.first_name, self.last_name, self.email)
+    }
+}
+
+/// State of a user.
+///
+/// States not known by this crate are kept in the `Unknown` variant, so that a new state in the
+/// API does not break the deserialization of the user information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserState {
+    /// Active user.
+    Active,
+    /// User pending verification.
+    Pending,
+    /// Locked user.
+    Locked,
+    /// Closed user account.
+    Closed,
+    /// Unknown user state.
+    Unknown(String),
+}
+
+impl_api_enum!(UserState {
+    Active => "ACTIVE",
+    Pending => "PENDING",
+    Locked => "LOCKED",
+    Closed => "CLOSED",
+});
+
 /// Structure representing an address.
 ///
 /// The structure can be converted back and forward to the JSON representation used by the Revolut
@@ -86,7 +227,28 @@ pub struct User {
 ///     streetLine2: "Apt. 5",
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Getters, Setters)]
+///
+/// Addresses can be created with the `AddressBuilder`, that sets each field by name, so that
+/// fields such as the city and the region can't be swapped by mistake. The second line of the
+/// street address is optional:
+///
+/// ```
+/// use revolut_customer::private::Address;
+///
+/// let address = Address::builder()
+///     .city("New City")
+///     .country("FR")
+///     .postcode("39325")
+///     .region("NewRegion")
+///     .street_line_1("Street 1, 6")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(address.city(), "New City");
+/// assert!(address.street_line_2().is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Builder, Getters, Setters)]
+#[builder(setter(into))]
 #[serde(rename_all = "camelCase")]
 pub struct Address {
     /// City of the address.
@@ -96,7 +258,7 @@ pub struct Address {
     /// Country of the address.
     #[get = "pub"]
     #[set = "pub"]
-    country: String, // TODO: enum
+    country: Country,
     /// Post code of the address.
     #[get = "pub"]
     #[set = "pub"]
@@ -112,11 +274,22 @@ pub struct Address {
     /// Street address, line 2.
     #[get = "pub"]
     #[set = "pub"]
+    #[builder(setter(strip_option), default)]
     street_line_2: Option<String>,
 }
 
 impl Address {
+    /// Creates a new address builder.
+    ///
+    /// This is equivalent to `AddressBuilder::default()`.
+    pub fn builder() -> AddressBuilder {
+        AddressBuilder::default()
+    }
+
     /// Creates a new address.
+    ///
+    /// The fields are positional, so the [`builder()`](#method.builder) is preferred to avoid
+    /// swapping them by mistake.
     pub fn new<CT, CN, P, R, SL1, SL2>(
         city: CT,
         country: CN,
@@ -127,7 +300,7 @@ impl Address {
     ) -> Self
     where
         CT: Into<String>,
-        CN: Into<String>,
+        CN: Into<Country>,
         P: Into<String>,
         R: Into<String>,
         SL1: Into<String>,
@@ -144,6 +317,105 @@ impl Address {
     }
 }
 
+/// Partial update of an address.
+///
+/// Only the fields that are set will be sent to the API, and the rest of the fields of the
+/// address will not be changed:
+///
+/// ```
+/// use revolut_customer::private::AddressUpdate;
+///
+/// let mut update = AddressUpdate::default();
+/// let _ = update.set_city("New City").set_postcode("39325");
+///
+/// assert_eq!(update.city().as_ref().unwrap(), "New City");
+/// assert!(update.region().is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressUpdate {
+    /// City of the address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[get = "pub"]
+    city: Option<String>,
+    /// Country of the address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[get = "pub"]
+    country: Option<Country>,
+    /// Post code of the address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[get = "pub"]
+    postcode: Option<String>,
+    /// Region of the address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[get = "pub"]
+    region: Option<String>,
+    /// Street address, line 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[get = "pub"]
+    street_line_1: Option<String>,
+    /// Street address, line 2.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[get = "pub"]
+    street_line_2: Option<String>,
+}
+
+impl AddressUpdate {
+    /// Sets the new city of the address.
+    pub fn set_city<C>(&mut self, city: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.city = Some(city.into());
+        self
+    }
+
+    /// Sets the new country of the address.
+    pub fn set_country<C>(&mut self, country: C) -> &mut Self
+    where
+        C: Into<Country>,
+    {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Sets the new post code of the address.
+    pub fn set_postcode<P>(&mut self, postcode: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.postcode = Some(postcode.into());
+        self
+    }
+
+    /// Sets the new region of the address.
+    pub fn set_region<R>(&mut self, region: R) -> &mut Self
+    where
+        R: Into<String>,
+    {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Sets the new first line of the street address.
+    pub fn set_street_line_1<S>(&mut self, street_line_1: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.street_line_1 = Some(street_line_1.into());
+        self
+    }
+
+    /// Sets the new second line of the street address.
+    pub fn set_street_line_2<S>(&mut self, street_line_2: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.street_line_2 = Some(street_line_2.into());
+        self
+    }
+}
+
 /// Wallet information structure.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
 #[serde(rename_all = "camelCase")]
@@ -157,7 +429,7 @@ pub struct Wallet {
     reference: String,
     /// State of the wallet.
     #[get = "pub"]
-    state: String,
+    state: WalletState,
     /// Base currency of the wallet.
     #[get = "pub"]
     base_currency: String, // TODO: enum
@@ -165,7 +437,7 @@ pub struct Wallet {
     #[get_copy = "pub"]
     total_topup: Amount,
     /// Topup reset date.
-    #[serde(with = "chrono::serde::ts_milliseconds")]
+    #[serde(deserialize_with = "deserialize_timestamp")]
     #[get_copy = "pub"]
     topup_reset_date: DateTime<Utc>,
     /// Pockets of the wallet.
@@ -173,10 +445,68 @@ pub struct Wallet {
 }
 
 impl Wallet {
+    /// Parses the wallet from its JSON representation, as returned by the API.
+    ///
+    /// This allows parsing captured API responses without a client.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).context(ApiError::ParseResponse)
+    }
+
     /// Pockets of the wallet.
     pub fn pockets(&self) -> &[Pocket] {
         &self.pockets
     }
+
+    /// Takes the pockets of the wallet.
+    pub fn into_pockets(self) -> Vec<Pocket> {
+        self.pockets.into_vec()
+    }
+
+    /// Gets the pocket with the given ID.
+    pub fn pocket_by_id(&self, id: Uuid) -> Option<&Pocket> {
+        self.pockets.iter().find(|pocket| pocket.id == id)
+    }
+
+    /// Gets the current pocket in the given currency.
+    ///
+    /// Savings pockets and closed pockets are ignored, so this returns the pocket that is used
+    /// for payments in the given currency, if there is one.
+    pub fn pocket_by_currency<C>(&self, currency: C) -> Option<&Pocket>
+    where
+        C: AsRef<str>,
+    {
+        self.pockets.iter().find(|pocket| {
+            !pocket.closed
+                && pocket.pocket_type == PocketType::Current
+                && pocket.currency == currency.as_ref()
+        })
+    }
+
+    /// Computes the total balance of the wallet in its base currency.
+    ///
+    /// It sums the balances of all the pockets in the base currency that are not closed. The
+    /// pockets in other currencies are left out, since their amounts can't be added without an
+    /// exchange rate; their balances can be computed with
+    /// [`total_balance_in()`](#method.total_balance_in). The sum saturates at the maximum amount
+    /// instead of overflowing.
+    pub fn total_balance(&self) -> Amount {
+        self.total_balance_in(&self.base_currency)
+    }
+
+    /// Computes the total balance of the wallet in the given currency.
+    ///
+    /// It sums the balances of all the pockets in the given currency that are not closed. The sum
+    /// saturates at the maximum amount instead of overflowing.
+    pub fn total_balance_in<C>(&self, currency: C) -> Amount
+    where
+        C: AsRef<str>,
+    {
+        self.pockets
+            .iter()
+            .filter(|pocket| !pocket.closed && pocket.currency == currency.as_ref())
+            .map(|pocket| pocket.balance)
+            .sum()
+    }
 }
 
 /// Pocket information structure.
@@ -189,10 +519,10 @@ pub struct Pocket {
     /// Pocket type.
     #[serde(rename = "type")]
     #[get = "pub"]
-    pocket_type: String,
+    pocket_type: PocketType,
     /// State of the pocket.
     #[get = "pub"]
-    state: String,
+    state: PocketState,
     /// Currency of the pocket.
     #[get = "pub"]
     currency: String,
@@ -210,6 +540,73 @@ pub struct Pocket {
     credit_limit: Amount,
 }
 
+impl Pocket {
+    /// Gets the balance of the pocket, together with its currency.
+    pub fn money_balance(&self) -> Money {
+        Money::new(self.balance, self.currency.clone())
+    }
+}
+
+/// State of a wallet.
+///
+/// States not known by this crate are kept in the `Unknown` variant, so that a new state in the
+/// API does not break the deserialization of the wallet information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalletState {
+    /// Active wallet.
+    Active,
+    /// Inactive wallet.
+    Inactive,
+    /// Unknown wallet state.
+    Unknown(String),
+}
+
+impl_api_enum!(WalletState {
+    Active => "ACTIVE",
+    Inactive => "INACTIVE",
+});
+
+/// Type of a pocket.
+///
+/// Types not known by this crate are kept in the `Unknown` variant, so that a new type in the API
+/// does not break the deserialization of the pocket information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PocketType {
+    /// Current account pocket.
+    Current,
+    /// Savings pocket.
+    Savings,
+    /// Unknown pocket type.
+    Unknown(String),
+}
+
+impl_api_enum!(PocketType {
+    Current => "CURRENT",
+    Savings => "SAVINGS",
+});
+
+/// State of a pocket.
+///
+/// States not known by this crate are kept in the `Unknown` variant, so that a new state in the
+/// API does not break the deserialization of the pocket information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PocketState {
+    /// Active pocket.
+    Active,
+    /// Inactive pocket.
+    Inactive,
+    /// Closed pocket.
+    Closed,
+    /// Unknown pocket state.
+    Unknown(String),
+}
+
+impl_api_enum!(PocketState {
+    Active => "ACTIVE",
+    Inactive => "INACTIVE",
+    Closed => "CLOSED",
+});
+
 /// Unknown `sof` structure.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters)]
 #[serde(rename_all = "camelCase")]
@@ -219,6 +616,79 @@ pub struct Sof {
     state: String,
 }
 
+/// Page of a list returned by the API.
+///
+/// The API does not send any pagination metadata, so it's computed by the client: the page is
+/// considered full, and therefore followed by more items, if it has as many items as requested.
+/// The cursor is opaque, and must be passed back to the method that returned the page to get the
+/// next one.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
+pub struct Page<T> {
+    /// Items of the page.
+    #[get = "pub"]
+    items: Vec<T>,
+    /// Cursor to get the next page, if there are more items.
+    #[get = "pub"]
+    next_cursor: Option<String>,
+    /// Whether there are more items after this page.
+    #[get_copy = "pub"]
+    has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// Creates a new page.
+    pub(crate) fn new(items: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            has_more: next_cursor.is_some(),
+            next_cursor,
+        }
+    }
+
+    /// Gets the items of the page, consuming it.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Deserializes a value, using its default if it's `null`.
+fn deserialize_null_default<'de, D, T>(de: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(de)?.unwrap_or_default())
+}
+
+/// Deserializes a date sent either as a timestamp in milliseconds or as an RFC 3339 string.
+///
+/// The API sends the dates as timestamps in milliseconds, but accepting RFC 3339 strings too
+/// avoids breaking the deserialization if the format changes.
+fn deserialize_timestamp<'de, D>(de: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    /// Representations of a date.
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    enum Timestamp {
+        /// Timestamp in milliseconds.
+        Millis(i64),
+        /// RFC 3339 string.
+        Rfc3339(String),
+    }
+
+    match Timestamp::deserialize(de)? {
+        Timestamp::Millis(millis) => Utc
+            .timestamp_millis_opt(millis)
+            .single()
+            .ok_or_else(|| de::Error::custom(format!("invalid timestamp: {}", millis))),
+        Timestamp::Rfc3339(date) => DateTime::parse_from_rfc3339(&date)
+            .map(|date| date.with_timezone(&Utc))
+            .map_err(de::Error::custom),
+    }
+}
+
 /// Deserializes the birth date of the user information structure.
 fn deserialize_user_birth_date<'de, D>(de: D) -> Result<NaiveDate, D::Error>
 where
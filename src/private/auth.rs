@@ -1,14 +1,20 @@
 //! Authorization methods of the API.
 
-use failure::{Error, ResultExt};
-use lazy_static::lazy_static;
-use reqwest::{StatusCode, Url};
-use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use anyhow::{Context, Error};
+use getset::{CopyGetters, Getters};
+use reqwest::header::ACCEPT;
+use serde::{Deserialize, Deserializer, Serialize};
+use uuid::Uuid;
 
 use super::{User, Wallet};
-use crate::{ApiError, Client, ErrResponse, BASE_API_URL};
+use crate::{handle_error_status, parse_json, phone::Phone, ApiError, Client};
 
 /// Authorization client methods
+///
+/// The sign-in methods don't require the client to have the authentication information, since
+/// they are the ones obtaining it. Refreshing the token and signing out do require it.
 impl Client {
     /// Signs the user in.
     ///
@@ -46,6 +52,8 @@ impl Client {
     /// The response status code will be in the `2XX` range if the phone/password were correct, or
     /// in the `4XX` range if they weren't or the API changed. The response will not have further
     /// information.
+    ///
+    /// The phone must be in the E.164 format, and it's validated before performing the request.
     pub fn sign_in<PH, PW>(&self, phone: PH, password: PW) -> Result<(), Error>
     where
         PH: AsRef<str>,
@@ -58,33 +66,80 @@ impl Client {
             password: &'d str,
         }
 
-        lazy_static! {
-            /// URL of the endpoint.
-            static ref URL: Url = BASE_API_URL.join("signin").unwrap();
+        let phone = phone
+            .as_ref()
+            .parse::<Phone>()
+            .context(ApiError::InvalidPhone)?;
+        let data = Data {
+            phone: phone.as_str(),
+            password: password.as_ref(),
+        };
+
+        let request_builder = self.client.post(self.endpoint("signin"));
+
+        let mut response = self.send(self.json_body(self.set_headers(request_builder), &data))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(handle_error_status(&mut response))
         }
+    }
 
+    /// Requests the API to send the sign-in confirmation code again.
+    ///
+    /// The code is sent by SMS after a successful [`sign_in()`](#method.sign_in), and this can be
+    /// used if it never arrived, without signing in again. The API limits how often the code can
+    /// be resent, so this might fail with an `ApiError::RateLimited` error.
+    ///
+    /// ## Request API specification
+    ///
+    /// No authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/signin/resend
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "phone": "+1555555555"
+    /// }
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the code was sent again, and the
+    /// response will not have further information. If there is no pending sign-in for the phone,
+    /// the response status code will be `400`, with a JSON object containing the error message
+    /// and code.
+    ///
+    /// The phone must be in the E.164 format, and it's validated before performing the request.
+    pub fn resend_sign_in_code<P>(&self, phone: P) -> Result<(), Error>
+    where
+        P: AsRef<str>,
+    {
+        /// Data to send to the endpoint in the JSON body.
+        #[derive(Debug, Serialize)]
+        struct Data<'d> {
+            phone: &'d str,
+        }
+
+        let phone = phone
+            .as_ref()
+            .parse::<Phone>()
+            .context(ApiError::InvalidPhone)?;
         let data = Data {
-            phone: phone.as_ref(),
-            password: password.as_ref(),
+            phone: phone.as_str(),
         };
 
-        let request_builder = self.client.post(URL.clone());
+        let request_builder = self.client.post(self.endpoint("signin/resend"));
 
-        let response = self
-            .set_headers(request_builder)
-            .json(&data)
-            .send()
-            .context(ApiError::RequestFailure)?;
+        let mut response = self.send(self.json_body(self.set_headers(request_builder), &data))?;
 
         if response.status().is_success() {
             Ok(())
-        } else if response.status() == StatusCode::UNAUTHORIZED {
-            Err(ApiError::Unauthorized.into())
         } else {
-            Err(ApiError::Other {
-                status_code: response.status(),
-            }
-            .into())
+            Err(handle_error_status(&mut response))
         }
     }
 
@@ -128,21 +183,44 @@ impl Client {
     /// object containing the user, wallet and access token for the user si returned. The
     /// implementation only returns the user and wallet objects, and saves the access token and
     /// user ID to authenticate in future requests.
-    pub fn confirm_sign_in<P, C>(&mut self, phone: P, code: C) -> Result<(User, Wallet), Error>
+    ///
+    /// New accounts might need an additional verification of the user, such as a selfie or a
+    /// picture of their ID document, before being logged in. In that case, the JSON object will
+    /// contain the verification challenge instead, and no access token will be saved:
+    ///
+    /// ```json
+    /// {
+    ///     "verification": {
+    ///         "id": "4c5d6e7f-8a9b-4c0d-9e1f-2a3b4c5d6e7f",
+    ///         "type": "SELFIE"
+    ///     }
+    /// }
+    /// ```
+    pub fn confirm_sign_in<P, C>(&mut self, phone: P, code: C) -> Result<ConfirmResult, Error>
     where
         P: AsRef<str>,
         C: AsRef<str>,
     {
         /// Response of the sign-in mechanism.
         #[derive(Debug, Deserialize)]
-        #[serde(rename_all = "camelCase")]
-        pub struct SignInResponse {
-            /// User information.
-            user: User,
-            /// Wallet information.
-            wallet: Wallet,
-            /// Access token.
-            access_token: String,
+        #[serde(untagged)]
+        #[allow(clippy::large_enum_variant)]
+        enum SignInResponse {
+            /// The user was logged in.
+            #[serde(rename_all = "camelCase")]
+            LoggedIn {
+                /// User information.
+                user: User,
+                /// Wallet information.
+                wallet: Wallet,
+                /// Access token.
+                access_token: String,
+            },
+            /// The user needs an additional verification.
+            VerificationRequired {
+                /// Verification challenge.
+                verification: VerificationChallenge,
+            },
         }
 
         /// Data to send to the endpoint in the JSON body.
@@ -152,41 +230,227 @@ impl Client {
             code: &'d str,
         }
 
-        lazy_static! {
-            /// URL of the endpoint.
-            static ref URL: Url = BASE_API_URL.join("signin/confirm").unwrap();
-        }
-
+        let phone = phone
+            .as_ref()
+            .parse::<Phone>()
+            .context(ApiError::InvalidPhone)?;
         let data = Data {
-            phone: phone.as_ref(),
+            phone: phone.as_str(),
             code: &code.as_ref().replace('-', ""),
         };
 
-        let request_builder = self.client.post(URL.clone());
-        let request_builder = self.set_headers(request_builder).json(&data);
+        let request_builder = self.client.post(self.endpoint("signin/confirm"));
+        let request_builder = self.json_body(self.set_headers(request_builder), &data);
 
-        let mut response = request_builder.send().context(ApiError::RequestFailure)?;
+        let mut response = self.send(request_builder)?;
 
         if response.status().is_success() {
-            let res_structure: SignInResponse = response.json().context(ApiError::ParseResponse)?;
-            self.user_id = Some(res_structure.user.id);
-            self.access_token = Some(res_structure.access_token);
+            match parse_json(&mut response)? {
+                SignInResponse::LoggedIn {
+                    user,
+                    wallet,
+                    access_token,
+                } => {
+                    self.user_id = Some(user.id);
+                    self.access_token = Some(access_token);
 
-            Ok((res_structure.user, res_structure.wallet))
-        } else if response.status() == StatusCode::BAD_REQUEST {
-            let err_response: ErrResponse = response.json().context(ApiError::ParseResponse)?;
-            Err(ApiError::BadRequest {
-                message: err_response.message,
-                code: err_response.code,
+                    Ok(ConfirmResult::LoggedIn(user, wallet))
+                }
+                SignInResponse::VerificationRequired { verification } => {
+                    Ok(ConfirmResult::VerificationRequired(verification))
+                }
             }
-            .into())
-        } else if response.status() == StatusCode::UNAUTHORIZED {
-            Err(ApiError::Unauthorized.into())
         } else {
-            Err(ApiError::Other {
-                status_code: response.status(),
-            }
-            .into())
+            Err(handle_error_status(&mut response))
         }
     }
+
+    /// Logs the user in, by signing in and confirming the sign in.
+    ///
+    /// After signing in, the `code_provider` closure is called to get the code that the user
+    /// received by SMS, so that the caller can ask for it in any way. The code is then used to
+    /// confirm the sign in, and the client will keep the authentication information, as in
+    /// [`confirm_sign_in()`](#method.confirm_sign_in). If the API requires an additional
+    /// verification of the user, the verification challenge will be returned.
+    ///
+    /// **Example:**
+    ///
+    /// ```no_run
+    /// # use anyhow::Error;
+    /// use std::io::stdin;
+    ///
+    /// use revolut_customer::Client;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let mut client = Client::default();
+    /// let result = client.login("+1555555555", "9999", || {
+    ///     let mut code = String::new();
+    ///     let _ = stdin().read_line(&mut code);
+    ///     code.trim().to_owned()
+    /// })?;
+    /// let (user, _wallet) = result.into_logged_in().expect("verification required");
+    ///
+    /// println!("Welcome, {}", user.first_name());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn login<PH, PW, F>(
+        &mut self,
+        phone: PH,
+        password: PW,
+        code_provider: F,
+    ) -> Result<ConfirmResult, Error>
+    where
+        PH: AsRef<str>,
+        PW: AsRef<str>,
+        F: FnOnce() -> String,
+    {
+        self.sign_in(phone.as_ref(), password)?;
+        let code = code_provider();
+        self.confirm_sign_in(phone, code)
+    }
+
+    /// Renews the access token of the client.
+    ///
+    /// This avoids going through the whole sign in process, with its SMS confirmation, in long
+    /// running sessions. The new access token replaces the current one in the client, and the
+    /// previous one should not be used anymore. If the access token already expired, the API will
+    /// reject the request with an `ApiError::TokenExpired` error, and the user will need to sign
+    /// in again. **Note**: Make sure the client has the authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/signin/refresh
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the access token was renewed, and a
+    /// JSON object with the new access token will be returned:
+    ///
+    /// ```json
+    /// {
+    ///     "accessToken": "9a4d5a6c-3b2e-4f1d-8c7b-6a5f4e3d2c1b"
+    /// }
+    /// ```
+    pub fn refresh_token(&mut self) -> Result<(), Error> {
+        /// Response of the token refresh mechanism.
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RefreshResponse {
+            /// New access token.
+            access_token: String,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.post(self.endpoint("signin/refresh"));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            let res_structure: RefreshResponse = parse_json(&mut response)?;
+            self.access_token = Some(res_structure.access_token);
+
+            Ok(())
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Signs the user out.
+    ///
+    /// This will invalidate the access token in the server, and will remove the user ID and the
+    /// access token from the client. The authentication information is removed from the client
+    /// even if the request fails, but the error is still returned.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/signout
+    /// ```
+    ///
+    /// The request has no body. The response status code will be in the `2XX` range if the access
+    /// token was invalidated, or in the `4XX` range if the authentication information was not
+    /// valid or the API changed. The response will not have further information.
+    pub fn sign_out(&mut self) -> Result<(), Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.post(self.endpoint("signout"));
+
+        let response = self.send(
+            self.set_headers(request_builder)
+                .basic_auth(user_id, Some(access_token)),
+        );
+
+        self.unset_auth();
+        let mut response = response?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+}
+
+/// Result of the sign-in confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum ConfirmResult {
+    /// The user was logged in, and the client stored the authentication information.
+    LoggedIn(User, Wallet),
+    /// The API requires an additional verification of the user before logging in.
+    VerificationRequired(VerificationChallenge),
 }
+
+impl ConfirmResult {
+    /// Checks whether the user was logged in.
+    pub fn is_logged_in(&self) -> bool {
+        matches!(self, ConfirmResult::LoggedIn(..))
+    }
+
+    /// Gets the user and the wallet, if the user was logged in.
+    pub fn into_logged_in(self) -> Option<(User, Wallet)> {
+        match self {
+            ConfirmResult::LoggedIn(user, wallet) => Some((user, wallet)),
+            ConfirmResult::VerificationRequired(_) => None,
+        }
+    }
+}
+
+/// Additional verification required to log the user in.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+pub struct VerificationChallenge {
+    /// ID of the verification.
+    #[get_copy = "pub"]
+    id: Uuid,
+    /// Type of the verification.
+    #[serde(rename = "type")]
+    #[get = "pub"]
+    kind: VerificationType,
+}
+
+/// Type of an additional verification of the user.
+///
+/// Types not known by this crate are kept in the `Unknown` variant, so that a new type in the API
+/// does not break the sign-in confirmation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationType {
+    /// Selfie of the user.
+    Selfie,
+    /// Picture of the ID document of the user.
+    IdDocument,
+    /// Unknown verification type.
+    Unknown(String),
+}
+
+impl_api_enum!(VerificationType {
+    Selfie => "SELFIE",
+    IdDocument => "ID_DOCUMENT",
+});
@@ -0,0 +1,120 @@
+//! Device methods of the API.
+
+use anyhow::Error;
+use chrono::{DateTime, Utc};
+use getset::{CopyGetters, Getters};
+use reqwest::header::ACCEPT;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::deserialize_timestamp;
+use crate::{handle_error_status, parse_json_list, Client};
+
+/// Device client methods.
+///
+/// They require the client to have loaded the authentication mechanisms.
+impl Client {
+    /// Gets the devices in which the current user is logged in.
+    ///
+    /// **Note**: Make sure the client has the authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/user/current/devices
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the user was authenticated, and a
+    /// JSON array with the devices will be returned:
+    ///
+    /// ```json
+    /// [
+    ///     {
+    ///         "id": "9a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d",
+    ///         "model": "iPhone10,6",
+    ///         "lastActive": 1547047020000,
+    ///         "current": true
+    ///     }
+    /// ]
+    /// ```
+    pub fn current_user_devices(&self) -> Result<Vec<Device>, Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.get(self.endpoint("user/current/devices"));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json_list(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Revokes the session of the current user in the given device.
+    ///
+    /// The device will be logged out, and its access token will no longer be valid. **Note**:
+    /// Make sure the client has the authentication information.
+    ///
+    /// If the client has a passcode set with
+    /// [`set_verify_password()`](#method.set_verify_password), it will be sent to verify the
+    /// operation.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// DELETE https://api.revolut.com/user/current/devices/{device-id}
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the device was logged out, with an
+    /// empty body. If the device does not exist, the response status code will be `404`.
+    pub fn revoke_device(&self, device_id: Uuid) -> Result<(), Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self
+            .client
+            .delete(self.endpoint(&format!("user/current/devices/{}", device_id)));
+
+        let mut response = self.send(
+            self.set_idempotency_key_header(
+                self.set_verify_password_header(self.set_headers(request_builder)),
+            )
+            .header(ACCEPT, "application/json")
+            .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+}
+
+/// Device information structure.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    /// Device ID.
+    #[get_copy = "pub"]
+    id: Uuid,
+    /// Model of the device.
+    #[get = "pub"]
+    model: String,
+    /// Date in which the device was last active.
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    #[get_copy = "pub"]
+    last_active: DateTime<Utc>,
+    /// Whether this is the device of the current session.
+    #[get_copy = "pub"]
+    current: bool,
+}
@@ -1 +1,185 @@
 //! Exchange methods of the API.
+
+use anyhow::Error;
+use getset::{CopyGetters, Getters};
+use reqwest::header::ACCEPT;
+use serde::{Deserialize, Serialize};
+
+use super::Transaction;
+use crate::{amount::Amount, handle_error_status, parse_json, Client};
+
+/// Exchange client methods.
+///
+/// They require the client to have loaded the authentication mechanisms.
+impl Client {
+    /// Gets a quote to exchange the given amount between two currencies.
+    ///
+    /// This method will not perform the exchange, it will only return the current rate and the
+    /// resulting amounts. **Note**: Make sure the client has the authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/quote?from=EUR&to=USD&amount=1000
+    /// ```
+    ///
+    /// The amount is sent in its internal representation (in cents). The response status code
+    /// will be in the `2XX` range if the quote could be computed, and a JSON object with the quote
+    /// will be returned:
+    ///
+    /// ```json
+    /// {
+    ///     "from": "EUR",
+    ///     "to": "USD",
+    ///     "rate": 1.17,
+    ///     "fromAmount": 1000,
+    ///     "toAmount": 1170,
+    ///     "fee": 0
+    /// }
+    /// ```
+    ///
+    /// If any of the currencies is not supported, the response status code will be `400`, with a
+    /// JSON object containing the error message and code.
+    pub fn exchange_quote<F, T>(
+        &self,
+        from: F,
+        to: T,
+        amount: Amount,
+    ) -> Result<ExchangeQuote, Error>
+    where
+        F: AsRef<str>,
+        T: AsRef<str>,
+    {
+        let (user_id, access_token) = self.auth_pair()?;
+        let mut url = self.endpoint("quote");
+        let _ = url
+            .query_pairs_mut()
+            .append_pair("from", from.as_ref())
+            .append_pair("to", to.as_ref())
+            .append_pair("amount", &amount.get_repr().to_string());
+
+        let request_builder = self.client.get(url);
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Exchanges money between two pockets, using a previously obtained quote.
+    ///
+    /// Since this moves money, the exchange can only be performed after getting a quote with
+    /// [`exchange_quote()`](#method.exchange_quote), and the quoted amounts will be the ones
+    /// sent to the API. If the rate changed since the quote was obtained, the API will reject the
+    /// exchange. It returns the transactions of both sides of the exchange. **Note**: Make sure
+    /// the client has the authentication information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/exchange
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "from": {
+    ///         "currency": "EUR",
+    ///         "amount": 1000
+    ///     },
+    ///     "to": {
+    ///         "currency": "USD",
+    ///         "amount": 1170
+    ///     },
+    ///     "rate": 1.17
+    /// }
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the exchange was performed, and a
+    /// JSON array with the transactions of both pockets will be returned. If there are not enough
+    /// funds or the rate changed, the response status code will be `400`, with a JSON object
+    /// containing the error message and code.
+    pub fn exchange(&self, quote: &ExchangeQuote) -> Result<Vec<Transaction>, Error> {
+        /// Side of the exchange.
+        #[derive(Debug, Serialize)]
+        struct Side<'d> {
+            currency: &'d str,
+            amount: Amount,
+        }
+
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData<'d> {
+            from: Side<'d>,
+            to: Side<'d>,
+            rate: f64,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.post(self.endpoint("exchange"));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData {
+                    from: Side {
+                        currency: &quote.from,
+                        amount: quote.from_amount,
+                    },
+                    to: Side {
+                        currency: &quote.to,
+                        amount: quote.to_amount,
+                    },
+                    rate: quote.rate,
+                },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+}
+
+/// Exchange quote information structure.
+#[derive(Debug, Clone, PartialEq, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeQuote {
+    /// Currency to exchange from.
+    #[get = "pub"]
+    from: String, // TODO: enum
+    /// Currency to exchange to.
+    #[get = "pub"]
+    to: String, // TODO: enum
+    /// Exchange rate.
+    #[get_copy = "pub"]
+    rate: f64,
+    /// Amount to exchange, in the original currency.
+    #[get_copy = "pub"]
+    from_amount: Amount,
+    /// Resulting amount, in the target currency.
+    #[get_copy = "pub"]
+    to_amount: Amount,
+    /// Fee of the exchange.
+    #[get_copy = "pub"]
+    fee: Amount,
+}
@@ -0,0 +1,119 @@
+//! Statement methods of the API.
+
+use std::fmt;
+
+use anyhow::{Context, Error};
+use chrono::{DateTime, Utc};
+use reqwest::header::ACCEPT;
+
+use crate::{handle_error_status, ApiError, Client};
+
+/// Statement client methods.
+///
+/// They require the client to have loaded the authentication mechanisms.
+impl Client {
+    /// Downloads the statement of the current user for the given period.
+    ///
+    /// Unlike the rest of the methods, the statement is not parsed, and the raw bytes of the
+    /// document in the requested format are returned, so that they can be directly stored in a
+    /// file. **Note**: Make sure the client has the authentication information.
+    ///
+    /// ```no_run
+    /// # use anyhow::Error;
+    /// use std::fs;
+    ///
+    /// use chrono::{TimeZone, Utc};
+    /// use revolut_customer::{private::StatementFormat, Client};
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let mut client = Client::default();
+    /// client.set_auth("b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d", "some-access-token")?;
+    ///
+    /// let statement = client.current_user_statement(
+    ///     Utc.ymd(2019, 1, 1).and_hms(0, 0, 0),
+    ///     Utc.ymd(2019, 2, 1).and_hms(0, 0, 0),
+    ///     StatementFormat::Pdf,
+    /// )?;
+    /// fs::write("statement-2019-01.pdf", statement)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/user/current/statements?from=1546300800000&to=1548979200000&format=pdf
+    /// ```
+    ///
+    /// The `from` and `to` parameters are timestamps in milliseconds, and the `format` parameter
+    /// is either `pdf` or `csv`. The `Accept` header is set to the MIME type of the format. The
+    /// response status code will be in the `2XX` range if the request was correct, and the body
+    /// will contain the statement document.
+    pub fn current_user_statement(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        format: StatementFormat,
+    ) -> Result<Vec<u8>, Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let mut url = self.endpoint("user/current/statements");
+        let _ = url
+            .query_pairs_mut()
+            .append_pair("from", &from.timestamp_millis().to_string())
+            .append_pair("to", &to.timestamp_millis().to_string())
+            .append_pair("format", format.as_str());
+
+        let request_builder = self.client.get(url);
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, format.mime_type())
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            let mut statement = Vec::new();
+            let _ = response
+                .copy_to(&mut statement)
+                .context(ApiError::RequestFailure)?;
+            Ok(statement)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+}
+
+/// Format of a statement document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatementFormat {
+    /// PDF document.
+    Pdf,
+    /// CSV file, with one transaction per line.
+    Csv,
+}
+
+impl StatementFormat {
+    /// Gets the name of the format, as sent to the API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StatementFormat::Pdf => "pdf",
+            StatementFormat::Csv => "csv",
+        }
+    }
+
+    /// Gets the MIME type of the format.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            StatementFormat::Pdf => "application/pdf",
+            StatementFormat::Csv => "text/csv",
+        }
+    }
+}
+
+impl fmt::Display for StatementFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -1 +1,464 @@
 //! Transaction methods of the API.
+
+use std::collections::VecDeque;
+
+use anyhow::{Context, Error};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use getset::{CopyGetters, Getters};
+use reqwest::header::ACCEPT;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{deserialize_timestamp, Page};
+use crate::{
+    amount::{Amount, SignedAmount},
+    country::Country,
+    handle_error_status, parse_json, parse_json_list, ApiError, Client,
+};
+
+/// Transaction client methods.
+///
+/// They require the client to have loaded the authentication mechanisms.
+impl Client {
+    /// Gets a page of the transactions of the current user.
+    ///
+    /// It returns at most `count` transactions, ordered from the newest to the oldest. If `to` is
+    /// provided, only the transactions started at or before that date will be returned. To get
+    /// the whole transaction history, [`transactions_iter()`](#method.transactions_iter) can be
+    /// used instead. **Note**: Make sure the client has the authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/user/current/transactions?count=50&to=1546300800000
+    /// ```
+    ///
+    /// The `to` parameter is a timestamp in milliseconds, and it's optional. The response status
+    /// code will be in the `2XX` range if the request was correct, and a JSON array with the
+    /// transactions will be returned.
+    pub fn transactions(
+        &self,
+        to: Option<DateTime<Utc>>,
+        count: u32,
+    ) -> Result<Vec<Transaction>, Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let mut url = self.endpoint("user/current/transactions");
+        {
+            let mut query_pairs = url.query_pairs_mut();
+            let _ = query_pairs.append_pair("count", &count.to_string());
+            if let Some(to) = to {
+                let _ = query_pairs.append_pair("to", &to.timestamp_millis().to_string());
+            }
+        }
+
+        let request_builder = self.client.get(url);
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json_list(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Gets a page of the transactions of the current user, with its pagination metadata.
+    ///
+    /// It returns at most `count` transactions, ordered from the newest to the oldest. The first
+    /// page is returned if no cursor is provided, and the next pages can be requested with the
+    /// [`next_cursor()`](private/struct.Page.html#method.next_cursor) of the previous page. The
+    /// transactions are requested with the [`transactions()`](#method.transactions) method.
+    ///
+    /// The API paginates the transactions by date, so the cursor holds the start date of the
+    /// oldest transaction in the page. Transactions started at that same date will be returned
+    /// again in the next page, to avoid missing any of them, so they should be deduplicated by
+    /// ID. The [`transactions_iter()`](#method.transactions_iter) method does this
+    /// automatically. If all the transactions of a full page started at the date of the given
+    /// cursor, the next cursor will point to the millisecond before it, so that the pagination
+    /// advances. In that case, other transactions started at that exact date can't be retrieved,
+    /// so a bigger `count` should be used. If the cursor is not valid, `ApiError::InvalidCursor`
+    /// will be returned.
+    /// **Note**: Make sure the client has the authentication information.
+    pub fn current_user_transactions(
+        &self,
+        cursor: Option<&str>,
+        count: u32,
+    ) -> Result<Page<Transaction>, Error> {
+        let to = match cursor {
+            Some(cursor) => {
+                let millis = cursor.parse::<i64>().context(ApiError::InvalidCursor)?;
+                Some(
+                    Utc.timestamp_millis_opt(millis)
+                        .single()
+                        .ok_or(ApiError::InvalidCursor)?,
+                )
+            }
+            None => None,
+        };
+
+        let transactions = self.transactions(to, count)?;
+        let next_cursor = if count > 0 && transactions.len() >= count as usize {
+            transactions
+                .iter()
+                .map(|t| t.started_date)
+                .min()
+                .map(|oldest| {
+                    // If the whole page started at the cursor date, the cursor must go back to
+                    // avoid requesting the same page forever.
+                    let next = if Some(oldest) == to {
+                        oldest - Duration::milliseconds(1)
+                    } else {
+                        oldest
+                    };
+                    next.timestamp_millis().to_string()
+                })
+        } else {
+            None
+        };
+
+        Ok(Page::new(transactions, next_cursor))
+    }
+
+    /// Gets an iterator over all the transactions of the current user.
+    ///
+    /// The transactions are lazily fetched in pages, from the newest to the oldest, using the
+    /// [`transactions()`](#method.transactions) method. **Note**: Make sure the client has the
+    /// authentication information.
+    ///
+    /// ```no_run
+    /// # use anyhow::Error;
+    /// use revolut_customer::Client;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let mut client = Client::default();
+    /// client.set_auth("b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d", "some-access-token")?;
+    ///
+    /// for transaction in client.transactions_iter().page_size(20) {
+    ///     println!("{}", transaction?.description());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transactions_iter(&self) -> TransactionsIter<'_> {
+        TransactionsIter {
+            client: self,
+            page_size: TransactionsIter::DEFAULT_PAGE_SIZE,
+            to: None,
+            boundary_ids: Vec::new(),
+            buffer: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Gets the details of one of the transactions of the current user.
+    ///
+    /// The transaction will contain the merchant, category and counterparty information, if the
+    /// API provides them for the transaction. **Note**: Make sure the client has the
+    /// authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/user/current/transactions/{transaction-id}
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the transaction exists, and a JSON
+    /// object with the transaction will be returned:
+    ///
+    /// ```json
+    /// {
+    ///     "id": "d7a8fbb3-07d4-4e8f-9a2e-3c5b6d7e8f90",
+    ///     "legId": "e1b2c3d4-5f6a-4b7c-8d9e-0f1a2b3c4d5e",
+    ///     "type": "CARD_PAYMENT",
+    ///     "state": "COMPLETED",
+    ///     "startedDate": 1546300800000,
+    ///     "updatedDate": 1546300800000,
+    ///     "currency": "EUR",
+    ///     "amount": -1000,
+    ///     "fee": 0,
+    ///     "description": "Coffee shop",
+    ///     "category": "restaurants",
+    ///     "merchant": {
+    ///         "name": "Coffee shop",
+    ///         "city": "Paris",
+    ///         "country": "FR"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// If the user has no transaction with the given ID, the response status code will be `404`.
+    pub fn current_user_transaction(&self, transaction_id: Uuid) -> Result<Transaction, Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self
+            .client
+            .get(self.endpoint(&format!("user/current/transactions/{}", transaction_id)));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Withdraws money from a pocket to a linked bank account.
+    ///
+    /// This method will move the given amount out of the given pocket, and will return the
+    /// resulting transaction, that can be used to track the withdrawal. **Note**: Make sure the
+    /// client has the authentication information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/withdrawal
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "pocketId": "f4a9a8b4-5e2a-4f5b-b4a5-0a0d8a7e3c11",
+    ///     "bankAccountId": "0b4c6f6e-9a6f-4e0b-8d2b-6f2f3c4d5e6f",
+    ///     "amount": 1000
+    /// }
+    /// ```
+    ///
+    /// The amount is sent in its internal representation (in cents). The response status code
+    /// will be in the `2XX` range if the withdrawal was accepted, and a JSON object with the
+    /// resulting transaction will be returned. If the pocket does not have enough funds or the
+    /// bank account is not valid, the response status code will be `400`, with a JSON object
+    /// containing the error message and code.
+    pub fn withdraw(
+        &self,
+        pocket_id: Uuid,
+        bank_account_id: Uuid,
+        amount: Amount,
+    ) -> Result<Transaction, Error> {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SentData {
+            pocket_id: Uuid,
+            bank_account_id: Uuid,
+            amount: Amount,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.post(self.endpoint("withdrawal"));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData {
+                    pocket_id,
+                    bank_account_id,
+                    amount,
+                },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+}
+
+/// Iterator over the transactions of the current user.
+///
+/// It's created with the [`Client::transactions_iter()`](../struct.Client.html#method.transactions_iter)
+/// method. Each page is requested with the date of the oldest transaction received so far, so
+/// that transactions started at the same time are not lost. The transactions that were already
+/// yielded are then skipped. The iteration finishes when the API returns a page with less
+/// transactions than requested, or after the first error.
+///
+/// If all the transactions of a full page started at the same date, and all of them were already
+/// yielded, the next page is requested from the millisecond before that date. Any other
+/// transaction started at that exact date can't be retrieved from the API, so the page size
+/// should be bigger than the number of transactions started at the same time.
+#[derive(Debug)]
+pub struct TransactionsIter<'c> {
+    /// Client used to fetch the pages.
+    client: &'c Client,
+    /// Number of transactions requested in each page.
+    page_size: u32,
+    /// Start date of the oldest transaction yielded so far.
+    to: Option<DateTime<Utc>>,
+    /// IDs of the yielded transactions started at the `to` date.
+    boundary_ids: Vec<Uuid>,
+    /// Transactions fetched but not yet yielded.
+    buffer: VecDeque<Transaction>,
+    /// Whether all the pages have been fetched.
+    finished: bool,
+}
+
+impl TransactionsIter<'_> {
+    /// Default number of transactions requested in each page.
+    pub const DEFAULT_PAGE_SIZE: u32 = 50;
+
+    /// Sets the number of transactions requested in each page.
+    ///
+    /// It should be set before starting the iteration.
+    #[must_use]
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Fetches the next page of transactions, and stores the new ones in the buffer.
+    fn fetch_page(&mut self) -> Result<(), Error> {
+        loop {
+            let page = self.client.transactions(self.to, self.page_size)?;
+            let full_page = self.page_size > 0 && page.len() >= self.page_size as usize;
+            if !full_page {
+                self.finished = true;
+            }
+
+            let to = self.to;
+            let boundary_ids = &self.boundary_ids;
+            let new: Vec<_> = page
+                .into_iter()
+                .filter(|t| Some(t.started_date) != to || !boundary_ids.contains(&t.id))
+                .collect();
+
+            if let Some(oldest) = new.iter().map(|t| t.started_date).min() {
+                if Some(oldest) != self.to {
+                    self.boundary_ids.clear();
+                    self.to = Some(oldest);
+                }
+                self.boundary_ids.extend(
+                    new.iter()
+                        .filter(|t| t.started_date == oldest)
+                        .map(|t| t.id),
+                );
+            } else if let (true, Some(to)) = (full_page, self.to) {
+                // The whole page was already yielded, since all its transactions started at the
+                // boundary date, so the next page must start before it.
+                self.boundary_ids.clear();
+                self.to = Some(to - Duration::milliseconds(1));
+                continue;
+            } else {
+                self.finished = true;
+            }
+
+            self.buffer.extend(new);
+            return Ok(());
+        }
+    }
+}
+
+impl Iterator for TransactionsIter<'_> {
+    type Item = Result<Transaction, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && !self.finished {
+            if let Err(e) = self.fetch_page() {
+                self.finished = true;
+                return Some(Err(e));
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
+
+/// Transaction information structure.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    /// Transaction ID.
+    #[get_copy = "pub"]
+    id: Uuid,
+    /// Leg ID of the transaction.
+    #[get_copy = "pub"]
+    leg_id: Uuid,
+    /// Type of the transaction.
+    #[serde(rename = "type")]
+    #[get = "pub"]
+    transaction_type: String, // TODO: enum
+    /// State of the transaction.
+    #[get = "pub"]
+    state: String, // TODO: enum
+    /// Date in which the transaction started.
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    #[get_copy = "pub"]
+    started_date: DateTime<Utc>,
+    /// Date in which the transaction was last updated.
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    #[get_copy = "pub"]
+    updated_date: DateTime<Utc>,
+    /// Currency of the transaction.
+    #[get = "pub"]
+    currency: String, // TODO: enum
+    /// Amount of the transaction.
+    ///
+    /// It will be a debit if the money left the account.
+    #[get_copy = "pub"]
+    amount: SignedAmount,
+    /// Fee of the transaction.
+    #[get_copy = "pub"]
+    fee: Amount,
+    /// Description of the transaction.
+    #[get = "pub"]
+    description: String,
+    /// Category of the transaction, if known.
+    #[get = "pub"]
+    category: Option<String>, // TODO: enum
+    /// Merchant of the transaction, for card payments.
+    #[get = "pub"]
+    merchant: Option<Merchant>,
+    /// Counterparty of the transaction, for transfers.
+    #[get = "pub"]
+    counterparty: Option<Counterparty>,
+}
+
+/// Merchant information structure.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct Merchant {
+    /// Name of the merchant.
+    #[get = "pub"]
+    name: String,
+    /// City of the merchant.
+    #[get = "pub"]
+    city: Option<String>,
+    /// Country of the merchant.
+    #[get = "pub"]
+    country: Option<Country>,
+}
+
+/// Counterparty information structure.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct Counterparty {
+    /// Counterparty ID, if it's a Revolut user or a known account.
+    #[get_copy = "pub"]
+    id: Option<Uuid>,
+    /// Type of the counterparty.
+    #[serde(rename = "type")]
+    #[get = "pub"]
+    counterparty_type: String, // TODO: enum
+    /// Name of the counterparty.
+    #[get = "pub"]
+    name: Option<String>,
+}
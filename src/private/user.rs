@@ -1,15 +1,21 @@
 //! User methods of the API.
 
+use std::{fmt, str::FromStr};
+
+use anyhow::{Context, Error};
 use chrono::{DateTime, NaiveDate, Utc};
-use failure::{Error, ResultExt};
 use getset::{CopyGetters, Getters};
-use lazy_static::lazy_static;
-use reqwest::{header::ACCEPT, StatusCode, Url};
-use serde::{Deserialize, Deserializer, Serialize};
+use reqwest::header::ACCEPT;
+use serde::{de, Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
-use super::{Address, User, Wallet};
-use crate::{amount::Amount, ApiError, Client, ErrResponse, BASE_API_URL};
+use super::{
+    deserialize_timestamp, Address, AddressUpdate, Pocket, PocketState, Transaction, User, Wallet,
+};
+use crate::{
+    amount::Amount, country::Country, email::Email, handle_error_status, parse_json,
+    parse_json_list, read_body, ApiError, Client,
+};
 
 /// User client methods.
 ///
@@ -19,113 +25,304 @@ impl Client {
     ///
     /// Make sure the client has the authentication information.
     pub fn current_user(&self) -> Result<(User, Wallet), Error> {
-        if let (&Some(ref user_id), &Some(ref access_token)) = (&self.user_id, &self.access_token) {
-            /// Response to the `current_user()` method.
-            #[derive(Debug, Deserialize)]
-            #[serde(rename_all = "camelCase")]
-            pub struct CurrentUserResponse {
-                /// User information.
-                user: User,
-                /// Wallet information.
-                wallet: Wallet,
-            }
-
-            lazy_static! {
-                /// URL of the endpoint.
-                static ref URL: Url = BASE_API_URL.join("user/current").unwrap();
-            }
-
-            let request_builder = self.client.get(URL.clone());
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.get(self.endpoint("user/current"));
 
-            let mut response = self
-                .set_headers(request_builder)
+        let mut response = self.send(
+            self.set_headers(request_builder)
                 .header(ACCEPT, "application/json")
-                .basic_auth(&user_id, Some(access_token))
-                .send()
-                .context(ApiError::RequestFailure)?;
-
-            if response.status().is_success() {
-                let res_structure: CurrentUserResponse =
-                    response.json().context(ApiError::ParseResponse)?;
-                Ok((res_structure.user, res_structure.wallet))
-            } else if response.status() == StatusCode::UNAUTHORIZED {
-                Err(ApiError::Unauthorized.into())
-            } else {
-                Err(ApiError::Other {
-                    status_code: response.status(),
-                }
-                .into())
-            }
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            let body = read_body(&mut response)?.ok_or(ApiError::EmptyResponse)?;
+            User::from_current_user_json(&body)
         } else {
-            Err(ApiError::NotLoggedIn.into())
+            Err(handle_error_status(&mut response))
         }
     }
 
     /// Gets user's wallet information.
     ///
-    /// Make sure the client has the authentication information.
+    /// Make sure the client has the authentication information. If the API responds with an empty
+    /// body, an `ApiError::EmptyResponse` error will be returned.
     pub fn current_user_wallet(&self) -> Result<Wallet, Error> {
-        if let (&Some(ref user_id), &Some(ref access_token)) = (&self.user_id, &self.access_token) {
-            lazy_static! {
-                /// URL of the endpoint.
-                static ref URL: Url = BASE_API_URL.join("user/current/wallet").unwrap();
-            }
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.get(self.endpoint("user/current/wallet"));
 
-            let request_builder = self.client.get(URL.clone());
-
-            let mut response = self
-                .set_headers(request_builder)
+        let mut response = self.send(
+            self.set_headers(request_builder)
                 .header(ACCEPT, "application/json")
-                .basic_auth(user_id, Some(&access_token))
-                .send()
-                .context(ApiError::RequestFailure)?;
-
-            if response.status().is_success() {
-                Ok(response.json().context(ApiError::ParseResponse)?)
-            } else if response.status() == StatusCode::UNAUTHORIZED {
-                Err(ApiError::Unauthorized.into())
-            } else {
-                Err(ApiError::Other {
-                    status_code: response.status(),
-                }
-                .into())
-            }
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
         } else {
-            Err(ApiError::NotLoggedIn.into())
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Closes one of the pockets of the current user's wallet.
+    ///
+    /// It returns the updated pocket. **Warning**: This changes the state of the account, and a
+    /// closed pocket can't be used for payments until it's opened again. The API might refuse to
+    /// close a pocket with a balance. **Note**: Make sure the client has the authentication
+    /// information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// PATCH https://api.revolut.com/user/current/wallet/pockets/{pocket-id}
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "state": "CLOSED"
+    /// }
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the pocket was closed, and a JSON
+    /// object with the pocket information will be returned. If the pocket can't be closed, the
+    /// response status code will be `400`, with a JSON object containing the error message and
+    /// code.
+    pub fn close_pocket(&self, pocket_id: Uuid) -> Result<Pocket, Error> {
+        self.change_pocket_state(pocket_id, &PocketState::Closed)
+    }
+
+    /// Opens again a closed pocket of the current user's wallet.
+    ///
+    /// It returns the updated pocket. **Warning**: This changes the state of the account.
+    /// **Note**: Make sure the client has the authentication information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// PATCH https://api.revolut.com/user/current/wallet/pockets/{pocket-id}
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "state": "ACTIVE"
+    /// }
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the pocket was opened, and a JSON
+    /// object with the pocket information will be returned. If the pocket can't be opened, the
+    /// response status code will be `400`, with a JSON object containing the error message and
+    /// code.
+    pub fn open_pocket(&self, pocket_id: Uuid) -> Result<Pocket, Error> {
+        self.change_pocket_state(pocket_id, &PocketState::Active)
+    }
+
+    /// Changes the state of one of the pockets of the current user's wallet.
+    fn change_pocket_state(&self, pocket_id: Uuid, state: &PocketState) -> Result<Pocket, Error> {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData<'d> {
+            state: &'d str,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self
+            .client
+            .patch(self.endpoint(&format!("user/current/wallet/pockets/{}", pocket_id)));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData {
+                    state: state.as_str(),
+                },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
         }
     }
 
     /// Gets user's cards information.
     ///
-    /// Make sure the client has the authentication information.
+    /// Make sure the client has the authentication information. If the API responds with an empty
+    /// body, the user is considered to have no cards.
     pub fn current_user_cards(&self) -> Result<Vec<Card>, Error> {
-        if let (&Some(ref user_id), &Some(ref access_token)) = (&self.user_id, &self.access_token) {
-            lazy_static! {
-                /// URL of the endpoint.
-                static ref URL: Url = BASE_API_URL.join("user/current/cards").unwrap();
-            }
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.get(self.endpoint("user/current/cards"));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
 
-            let request_builder = self.client.get(URL.clone());
+        if response.status().is_success() {
+            parse_json_list(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
 
-            let mut response = self
-                .set_headers(request_builder)
+    /// Gets the addresses saved by the current user.
+    ///
+    /// Besides the address in the user information, users can have more saved addresses, such as
+    /// the delivery addresses of their cards. **Note**: Make sure the client has the
+    /// authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/user/current/addresses
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the user was authenticated, and a
+    /// JSON array with the addresses will be returned. If the API responds with an empty body, the
+    /// user is considered to have no saved addresses:
+    ///
+    /// ```json
+    /// [
+    ///     {
+    ///         "city": "New City",
+    ///         "country": "FR",
+    ///         "postcode": "39325",
+    ///         "region": "NewRegion",
+    ///         "streetLine1": "Street 1, 6",
+    ///         "streetLine2": "Apt. 5"
+    ///     }
+    /// ]
+    /// ```
+    pub fn current_user_addresses(&self) -> Result<Vec<Address>, Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.get(self.endpoint("user/current/addresses"));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
                 .header(ACCEPT, "application/json")
-                .basic_auth(user_id, Some(&access_token))
-                .send()
-                .context(ApiError::RequestFailure)?;
-
-            if response.status().is_success() {
-                Ok(response.json().context(ApiError::ParseResponse)?)
-            } else if response.status() == StatusCode::UNAUTHORIZED {
-                Err(ApiError::Unauthorized.into())
-            } else {
-                Err(ApiError::Other {
-                    status_code: response.status(),
-                }
-                .into())
-            }
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json_list(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Gets the user, wallet and cards information at once.
+    ///
+    /// The user and the wallet are retrieved with [`current_user()`](#method.current_user), and
+    /// the cards with [`current_user_cards()`](#method.current_user_cards), so two requests are
+    /// sent to the API. If any of them fails, its error is returned, and no further requests are
+    /// sent. Make sure the client has the authentication information.
+    pub fn current_user_full(&self) -> Result<UserFull, Error> {
+        let (user, wallet) = self.current_user()?;
+        let cards = self.current_user_cards()?;
+
+        Ok(UserFull {
+            user,
+            wallet,
+            cards,
+        })
+    }
+
+    /// Gets the top-up limits of the current user.
+    ///
+    /// **Note**: Make sure the client has the authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/user/current/limits
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the user was authenticated, and a
+    /// JSON object with the limits will be returned. The amounts are in the base currency of the
+    /// wallet, except for the ones of each currency:
+    ///
+    /// ```json
+    /// {
+    ///     "topupUsed": 150000,
+    ///     "topupMax": 500000,
+    ///     "resetDate": 1548979200000,
+    ///     "currencies": [
+    ///         {
+    ///             "currency": "EUR",
+    ///             "used": 150000,
+    ///             "max": 500000
+    ///         }
+    ///     ]
+    /// }
+    /// ```
+    pub fn current_user_limits(&self) -> Result<Limits, Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.get(self.endpoint("user/current/limits"));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Gets the information of one of the user's cards.
+    ///
+    /// Make sure the client has the authentication information.
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/user/current/cards/{card-id}
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the card exists, and a JSON object
+    /// with the card information will be returned. If the user has no card with the given ID,
+    /// the response status code will be `404`.
+    pub fn current_user_card(&self, card_id: Uuid) -> Result<Card, Error> {
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self
+            .client
+            .get(self.endpoint(&format!("user/current/cards/{}", card_id)));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json")
+                .basic_auth(user_id, Some(access_token)),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
         } else {
-            Err(ApiError::NotLoggedIn.into())
+            Err(handle_error_status(&mut response))
         }
     }
 
@@ -176,6 +373,9 @@ impl Client {
     ///
     /// Note that the response will be a 400 error, since the phone/code combination is not correct.
     ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
     /// ## Request API specification
     ///
     /// No authentication required.
@@ -202,57 +402,557 @@ impl Client {
     /// The definitions for these objects is shown in the methods that specifically return each of
     /// the types.
     pub fn change_current_user_address(&self, address: &Address) -> Result<(), Error> {
-        if let (&Some(ref user_id), &Some(ref access_token)) = (&self.user_id, &self.access_token) {
-            /// Data structure to send to the API.
-            #[derive(Debug, Serialize)]
-            struct SentData<'d> {
-                address: &'d Address,
-            }
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData<'d> {
+            address: &'d Address,
+        }
 
-            lazy_static! {
-                /// URL of the endpoint.
-                static ref URL: Url = BASE_API_URL.join("user/current").unwrap();
-            }
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.patch(self.endpoint("user/current"));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData { address },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
 
-            let request_builder = self.client.patch(URL.clone());
+    /// Changes some of the fields of the address of the current user.
+    ///
+    /// Unlike [`change_current_user_address()`](#method.change_current_user_address), only the
+    /// fields set in the update will be changed, so the current address does not need to be
+    /// retrieved first. **Note**: Make sure the client has the authentication information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// PATCH https://api.revolut.com/user/current
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "address": {
+    ///         "city": "New City",
+    ///         "postcode": "39325"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the address was changed, and the
+    /// response will not have further information. If any of the fields is not valid, the
+    /// response status code will be `400`, with a JSON object containing the error message and
+    /// code.
+    pub fn patch_current_user_address(&self, update: &AddressUpdate) -> Result<(), Error> {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData<'d> {
+            address: &'d AddressUpdate,
+        }
 
-            let mut response = self
-                .set_headers(request_builder)
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.patch(self.endpoint("user/current"));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData { address: update },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Changes the email address of the current user.
+    ///
+    /// The email address is validated before being sent, and the API will then send a
+    /// verification email to the new address. Until it's verified, the `email_verified` field of
+    /// the user will be `false`. **Note**: Make sure the client has the authentication
+    /// information.
+    ///
+    /// If the client has a passcode set with
+    /// [`set_verify_password()`](#method.set_verify_password), it will be sent to verify the
+    /// operation.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// PATCH https://api.revolut.com/user/current
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "email": "john.doe@example.com"
+    /// }
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the email address was changed, and
+    /// the response will not have further information. If the email address is not accepted, for
+    /// example because it's already in use, the response status code will be `400`, with a JSON
+    /// object containing the error message and code.
+    pub fn change_current_user_email<E>(&self, email: E) -> Result<(), Error>
+    where
+        E: AsRef<str>,
+    {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData<'d> {
+            email: &'d str,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let email = email
+            .as_ref()
+            .parse::<Email>()
+            .context(ApiError::InvalidEmail)?;
+        let request_builder = self.client.patch(self.endpoint("user/current"));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(
+                    self.set_verify_password_header(self.set_headers(request_builder)),
+                )
                 .header(ACCEPT, "application/json")
-                .basic_auth(user_id, Some(&access_token))
-                .json(&SentData { address })
-                .send()
-                .context(ApiError::RequestFailure)?;
-
-            if response.status().is_success() {
-                Ok(())
-            } else if response.status() == StatusCode::UNAUTHORIZED {
-                Err(ApiError::Unauthorized.into())
-            } else if response.status() == StatusCode::BAD_REQUEST {
-                let err_response: ErrResponse = response.json().context(ApiError::ParseResponse)?;
-                Err(ApiError::BadRequest {
-                    code: err_response.code,
-                    message: err_response.message,
-                }
-                .into())
-            } else {
-                Err(ApiError::Other {
-                    status_code: response.status(),
-                }
-                .into())
-            }
+                .basic_auth(user_id, Some(access_token)),
+                &SentData {
+                    email: email.as_str(),
+                },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            Ok(())
         } else {
-            Err(ApiError::NotLoggedIn.into())
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Tops up the wallet of the current user using one of their cards.
+    ///
+    /// This method will charge the given amount to the card with the given ID, and will return the
+    /// resulting top-up transaction. **Note**: Make sure the client has the authentication
+    /// information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/user/current/wallet/topup
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "cardId": "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d",
+    ///     "amount": 5000,
+    ///     "currency": "EUR"
+    /// }
+    /// ```
+    ///
+    /// The amount is sent in its internal representation (in cents). The response status code
+    /// will be in the `2XX` range if the top-up was accepted, and a JSON object with the resulting
+    /// transaction will be returned. If the card can't be charged or the amount exceeds the
+    /// top-up limits, the response status code will be `400`, with a JSON object containing the
+    /// error message and code.
+    pub fn top_up<C>(
+        &self,
+        card_id: Uuid,
+        amount: Amount,
+        currency: C,
+    ) -> Result<Transaction, Error>
+    where
+        C: AsRef<str>,
+    {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SentData<'d> {
+            card_id: Uuid,
+            amount: Amount,
+            currency: &'d str,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self.client.post(self.endpoint("user/current/wallet/topup"));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData {
+                    card_id,
+                    amount,
+                    currency: currency.as_ref(),
+                },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Confirms a newly added card of the current user.
+    ///
+    /// When a card is added, Revolut charges it a small random amount, that the user can check in
+    /// their bank statement. Sending that amount confirms that the user owns the card. The
+    /// updated card will be returned. **Note**: Make sure the client has the authentication
+    /// information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/user/current/cards/{card-id}/confirm
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "amount": 123
+    /// }
+    /// ```
+    ///
+    /// The amount is sent in its internal representation (in cents). The response status code
+    /// will be in the `2XX` range if the amount was correct, and a JSON object with the updated
+    /// card will be returned. If the amount was not correct, or if the confirmation attempts of
+    /// the card (see [`Card::confirmation_attempts()`](struct.Card.html#method.confirmation_attempts))
+    /// were exhausted, the response status code will be `400`, with a JSON object containing the
+    /// error message and code, that will be returned as an
+    /// [`ApiError::BadRequest`](../enum.ApiError.html#variant.BadRequest).
+    pub fn confirm_card(&self, card_id: Uuid, amount: Amount) -> Result<Card, Error> {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData {
+            amount: Amount,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self
+            .client
+            .post(self.endpoint(&format!("user/current/cards/{}/confirm", card_id)));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData { amount },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Verifies a card of the current user with the verification code sent by Revolut.
+    ///
+    /// Some cards are verified with a code instead of with the charged amount of
+    /// [`confirm_card()`](#method.confirm_card). The updated card, that will be confirmed, will be
+    /// returned. **Note**: Make sure the client has the authentication information.
+    ///
+    /// The API does not send a known error code when the confirmation attempts are exhausted, so
+    /// the attempts are tracked with
+    /// [`Card::confirmation_attempts()`](struct.Card.html#method.confirmation_attempts). After a
+    /// failed verification, the card can be fetched again with
+    /// [`current_user_card()`](#method.current_user_card) to check how many attempts were
+    /// consumed before trying again:
+    ///
+    /// ```no_run
+    /// # use anyhow::Error;
+    /// use revolut_customer::Client;
+    ///
+    /// # fn main() -> Result<(), Error> {
+    /// let mut client = Client::default();
+    /// client.set_auth("b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d", "some-access-token")?;
+    ///
+    /// let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d".parse()?;
+    /// if client.verify_card(card_id, "123456").is_err() {
+    ///     let card = client.current_user_card(card_id)?;
+    ///     println!("Attempts consumed: {}", card.confirmation_attempts());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// POST https://api.revolut.com/user/current/cards/{card-id}/verify
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "code": "123456"
+    /// }
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range if the code was correct, and a JSON
+    /// object with the updated card will be returned. Each wrong code consumes one of the
+    /// confirmation attempts of the card (see
+    /// [`Card::confirmation_attempts()`](struct.Card.html#method.confirmation_attempts)). If the
+    /// code was not correct, or if the attempts were exhausted, the response status code will be
+    /// `400`, with a JSON object containing the error message and code, that will be returned as
+    /// an [`ApiError::BadRequest`](../enum.ApiError.html#variant.BadRequest). If the card does
+    /// not exist, the response status code will be `404`.
+    pub fn verify_card<C>(&self, card_id: Uuid, code: C) -> Result<Card, Error>
+    where
+        C: AsRef<str>,
+    {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData<'d> {
+            code: &'d str,
+        }
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self
+            .client
+            .post(self.endpoint(&format!("user/current/cards/{}/verify", card_id)));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData {
+                    code: code.as_ref(),
+                },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+
+    /// Enables or disables the auto-topup of a card of the current user.
+    ///
+    /// When enabled, the wallet will be topped up from the card with the given amount each time
+    /// its balance goes below the given threshold. Both the threshold and the amount must be
+    /// provided together, or none of them to keep the current ones; otherwise, an
+    /// `ApiError::InvalidAutoTopup` error will be returned without performing the request. The
+    /// updated card will be returned. **Note**: Make sure the client has the authentication
+    /// information.
+    ///
+    /// The request is sent with an idempotency key, see
+    /// [`with_idempotency_key()`](#method.with_idempotency_key).
+    ///
+    /// ## Request API specification
+    ///
+    /// Authentication required.
+    ///
+    /// ```text
+    /// PATCH https://api.revolut.com/user/current/cards/{card-id}
+    /// ```
+    ///
+    /// **Body (JSON encoded):**
+    ///
+    /// ```json
+    /// {
+    ///     "autoTopup": "ENABLED",
+    ///     "autoTopupThreshold": 1000,
+    ///     "autoTopupAmount": 5000
+    /// }
+    /// ```
+    ///
+    /// The status is either `ENABLED` or `DISABLED`, and the threshold and the amount are sent in
+    /// their internal representation, only if provided. The response status code will be in the
+    /// `2XX` range if the configuration was changed, and a JSON object with the updated card will
+    /// be returned. If the API rejects the configuration, the response status code will be `400`,
+    /// with a JSON object containing the error message. If the card does not exist, the response
+    /// status code will be `404`.
+    pub fn set_auto_topup(
+        &self,
+        card_id: Uuid,
+        enabled: bool,
+        threshold: Option<Amount>,
+        amount: Option<Amount>,
+    ) -> Result<Card, Error> {
+        /// Data structure to send to the API.
+        #[derive(Debug, Serialize)]
+        struct SentData<'d> {
+            #[serde(rename = "autoTopup")]
+            status: &'d str,
+            #[serde(rename = "autoTopupThreshold", skip_serializing_if = "Option::is_none")]
+            threshold: Option<Amount>,
+            #[serde(rename = "autoTopupAmount", skip_serializing_if = "Option::is_none")]
+            amount: Option<Amount>,
+        }
+
+        if threshold.is_some() != amount.is_some() {
+            return Err(ApiError::InvalidAutoTopup.into());
+        }
+
+        let status = if enabled {
+            AutoTopupStatus::Enabled
+        } else {
+            AutoTopupStatus::Disabled
+        };
+
+        let (user_id, access_token) = self.auth_pair()?;
+        let request_builder = self
+            .client
+            .patch(self.endpoint(&format!("user/current/cards/{}", card_id)));
+
+        let mut response = self.send(
+            self.json_body(
+                self.set_idempotency_key_header(self.set_headers(request_builder))
+                    .header(ACCEPT, "application/json")
+                    .basic_auth(user_id, Some(access_token)),
+                &SentData {
+                    status: status.as_str(),
+                    threshold,
+                    amount,
+                },
+            ),
+        )?;
+
+        if response.status().is_success() {
+            parse_json(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
         }
     }
 }
 
+/// User information, together with the wallet and the cards of the user.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+pub struct UserFull {
+    /// User information.
+    #[get = "pub"]
+    user: User,
+    /// Wallet of the user.
+    #[get = "pub"]
+    wallet: Wallet,
+    /// Cards of the user.
+    #[get = "pub"]
+    cards: Vec<Card>,
+}
+
+impl UserFull {
+    /// Splits the information into the user, the wallet and the cards.
+    pub fn into_parts(self) -> (User, Wallet, Vec<Card>) {
+        (self.user, self.wallet, self.cards)
+    }
+}
+
+/// Top-up limits of the user.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct Limits {
+    /// Amount topped up since the last reset, in the base currency.
+    #[get_copy = "pub"]
+    topup_used: Amount,
+    /// Maximum amount that can be topped up until the next reset, in the base currency.
+    #[get_copy = "pub"]
+    topup_max: Amount,
+    /// Date in which the used amounts will be reset.
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    #[get_copy = "pub"]
+    reset_date: DateTime<Utc>,
+    /// Limits for each currency.
+    #[serde(default)]
+    #[get = "pub"]
+    currencies: Vec<CurrencyLimit>,
+}
+
+impl Limits {
+    /// Gets the amount that can still be topped up until the next reset, in the base currency.
+    pub fn topup_remaining(&self) -> Amount {
+        Amount::from_repr(
+            self.topup_max
+                .get_repr()
+                .saturating_sub(self.topup_used.get_repr()),
+        )
+    }
+
+    /// Gets the limit for the given currency, if there is one.
+    pub fn currency<C>(&self, currency: C) -> Option<&CurrencyLimit>
+    where
+        C: AsRef<str>,
+    {
+        self.currencies
+            .iter()
+            .find(|limit| limit.currency == currency.as_ref())
+    }
+}
+
+/// Top-up limit for a currency.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrencyLimit {
+    /// Currency of the limit.
+    #[get = "pub"]
+    currency: String, // TODO: enum
+    /// Amount topped up in the currency since the last reset.
+    #[get_copy = "pub"]
+    used: Amount,
+    /// Maximum amount that can be topped up in the currency until the next reset.
+    #[get_copy = "pub"]
+    max: Amount,
+}
+
 /// Credit card representation.
-#[derive(Debug, Clone, PartialEq, Deserialize, Getters, CopyGetters)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
 #[serde(rename_all = "camelCase")]
 pub struct Card {
     /// Card ID.
-    #[get_copy]
+    #[get_copy = "pub"]
     id: Uuid,
     /// Owner's user ID.
     #[get_copy = "pub"]
@@ -262,11 +962,10 @@ pub struct Card {
     last_four: String,
     /// Brand of the card.
     #[get = "pub"]
-    brand: String, // TODO: enum
+    brand: CardBrand,
     /// Expiry date of the card.
-    #[serde(deserialize_with = "deserialize_card_expiry_date")]
     #[get_copy = "pub"]
-    expiry_date: NaiveDate, // TODO, only month and year
+    expiry_date: ExpiryDate,
     /// Wether the card is expired.
     #[get_copy = "pub"]
     expired: bool,
@@ -291,25 +990,23 @@ pub struct Card {
     /// Number of attempts performed to confirm the card.
     #[get_copy = "pub"]
     confirmation_attempts: u8,
-    /// Auto-topup status.
+    /// Auto-topup status, with its reason.
+    #[serde(flatten)]
     #[get = "pub"]
-    auto_topup: String, // TODO: enum
-    /// Reason for the auto-topup status.
-    #[get = "pub"]
-    auto_topup_reason: String,
+    auto_topup: AutoTopup,
     /// Card creation date.
-    #[serde(with = "chrono::serde::ts_milliseconds")]
+    #[serde(deserialize_with = "deserialize_timestamp")]
     #[get_copy = "pub"]
     created_date: DateTime<Utc>,
     /// Card update date.
-    #[serde(with = "chrono::serde::ts_milliseconds")]
+    #[serde(deserialize_with = "deserialize_timestamp")]
     #[get_copy = "pub"]
     updated_date: DateTime<Utc>,
     /// Type of the associated bank.
     #[get = "pub"]
     associated_bank_type: String, // TODO: enum
     /// Last time used.
-    #[serde(with = "chrono::serde::ts_milliseconds")]
+    #[serde(deserialize_with = "deserialize_timestamp")]
     #[get_copy = "pub"]
     last_used_date: DateTime<Utc>,
     /// Current topup amount.
@@ -320,8 +1017,36 @@ pub struct Card {
     credit_repayment: bool,
 }
 
+impl Card {
+    /// Parses the card from its JSON representation, as returned by the API.
+    ///
+    /// This allows parsing captured API responses without a client.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).context(ApiError::ParseResponse)
+    }
+
+    /// Gets the last day in which the card is valid.
+    pub fn expiry_last_day(&self) -> NaiveDate {
+        self.expiry_date.last_day()
+    }
+}
+
+impl fmt::Display for Card {
+    /// Formats the card as `BRAND ****last_four (exp MM/YY)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ****{} (exp {:02}/{:02})",
+            self.brand,
+            self.last_four,
+            self.expiry_date.month,
+            self.expiry_date.year.rem_euclid(100)
+        )
+    }
+}
+
 /// Credit card issuer information.
-#[derive(Debug, Clone, PartialEq, Deserialize, Getters, CopyGetters)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
 #[serde(rename_all = "camelCase")]
 pub struct Issuer {
     /// Bank Identification Number
@@ -335,10 +1060,10 @@ pub struct Issuer {
     card_type: CardType,
     /// Brand of the card.
     #[get = "pub"]
-    card_brand: String, // TODO: enum
+    card_brand: CardBrand,
     /// Country of the card.
     #[get = "pub"]
-    country: String, // TODO: enum
+    country: Country,
     /// Currency of the card.
     #[get = "pub"]
     currency: String, // TODO: enum
@@ -346,16 +1071,82 @@ pub struct Issuer {
     #[get_copy = "pub"]
     supported: bool,
     /// Fee for using the card.
+    ///
+    /// The API sends it as a floating point number, that is rounded to two decimal digits so that
+    /// issuers can be compared exactly.
     #[get_copy = "pub"]
-    fee: f64,
+    #[serde(deserialize_with = "deserialize_fee")]
+    fee: Amount,
     /// Wether the postcode is required for operation.
     #[get_copy = "pub"]
     postcode_required: bool,
 }
 
+/// Auto-topup configuration of a card.
+///
+/// The API sends it in the `autoTopup` and `autoTopupReason` fields of the card.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters)]
+pub struct AutoTopup {
+    /// Status of the auto-topup.
+    #[serde(rename = "autoTopup")]
+    #[get = "pub"]
+    status: AutoTopupStatus,
+    /// Reason for the auto-topup status.
+    ///
+    /// It will be `None` if the API does not send it, or if it's empty.
+    #[serde(
+        rename = "autoTopupReason",
+        default,
+        deserialize_with = "deserialize_non_empty"
+    )]
+    #[get = "pub"]
+    reason: Option<String>,
+}
+
+impl AutoTopup {
+    /// Checks whether the auto-topup is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.status == AutoTopupStatus::Enabled
+    }
+}
+
+/// Status of the auto-topup of a card.
+///
+/// Statuses not known by this crate are kept in the `Unknown` variant, so that a new status in the
+/// API does not break the deserialization of the cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutoTopupStatus {
+    /// The auto-topup is enabled.
+    Enabled,
+    /// The auto-topup is disabled.
+    Disabled,
+    /// The auto-topup is temporarily suspended, usually for the reason in the configuration.
+    Suspended,
+    /// Unknown auto-topup status.
+    Unknown(String),
+}
+
+impl_api_enum!(AutoTopupStatus {
+    Enabled => "ENABLED",
+    Disabled => "DISABLED",
+    Suspended => "SUSPENDED",
+});
+
 /// Card type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+///
+/// It can be converted to and from the representation used by the API:
+///
+/// ```
+/// use revolut_customer::private::CardType;
+///
+/// assert_eq!(format!("{}", CardType::Credit), "CREDIT");
+/// assert_eq!("DEBIT".parse::<CardType>().unwrap(), CardType::Debit);
+/// ```
+///
+/// Revolut may issue new types of cards, so the enum is `#[non_exhaustive]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
 pub enum CardType {
     /// Credit card.
     Credit,
@@ -363,30 +1154,172 @@ pub enum CardType {
     Debit,
 }
 
-/// Deserializes the expiry date of the card information structure.
-fn deserialize_card_expiry_date<'de, D>(de: D) -> Result<NaiveDate, D::Error>
+impl fmt::Display for CardType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardType::Credit => write!(f, "CREDIT"),
+            CardType::Debit => write!(f, "DEBIT"),
+        }
+    }
+}
+
+impl FromStr for CardType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "CREDIT" => Ok(CardType::Credit),
+            "DEBIT" => Ok(CardType::Debit),
+            _ => Err(CardTypeParseError {
+                card_type_str: s.to_owned(),
+            }
+            .into()),
+        }
+    }
+}
+
+/// Card type parsing error.
+#[derive(Debug, Clone, thiserror::Error, PartialEq)]
+#[error("the card type {card_type_str} is not a valid card type")]
+pub struct CardTypeParseError {
+    pub(crate) card_type_str: String,
+}
+
+/// Card brand.
+///
+/// Brands not known by this crate are kept in the `Unknown` variant, so that a new brand in the
+/// API does not break the deserialization of the card information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CardBrand {
+    /// Visa card.
+    Visa,
+    /// Mastercard card.
+    Mastercard,
+    /// Maestro card.
+    Maestro,
+    /// American Express card.
+    AmericanExpress,
+    /// Discover card.
+    Discover,
+    /// Diners Club card.
+    DinersClub,
+    /// JCB card.
+    Jcb,
+    /// Union Pay card.
+    UnionPay,
+    /// Unknown card brand.
+    Unknown(String),
+}
+
+impl_api_enum!(CardBrand {
+    Visa => "VISA",
+    Mastercard => "MASTERCARD",
+    Maestro => "MAESTRO",
+    AmericanExpress => "AMEX",
+    Discover => "DISCOVER",
+    DinersClub => "DINERS",
+    Jcb => "JCB",
+    UnionPay => "UNIONPAY",
+});
+
+/// Expiry date of a card.
+///
+/// Cards expire at the end of the given month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CopyGetters)]
+pub struct ExpiryDate {
+    /// Year of the expiry date.
+    #[get_copy = "pub"]
+    year: i32,
+    /// Month of the expiry date, from 1 to 12.
+    #[get_copy = "pub"]
+    month: u32,
+    /// Last day in which the card is valid.
+    last_day: NaiveDate,
+}
+
+impl ExpiryDate {
+    /// Creates a new expiry date.
+    ///
+    /// Returns `None` if the month is not between 1 and 12, or if the year is out of the range of
+    /// supported dates.
+    pub fn new(year: i32, month: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+        let (next_year, next_month) = if month == 12 {
+            (year.checked_add(1)?, 1)
+        } else {
+            (year, month + 1)
+        };
+        let last_day = NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()?;
+
+        Some(Self {
+            year,
+            month,
+            last_day,
+        })
+    }
+
+    /// Gets the last day in which the card is valid.
+    pub fn last_day(self) -> NaiveDate {
+        self.last_day
+    }
+
+    /// Checks if the card is expired at the given moment.
+    pub fn is_expired_at(self, now: DateTime<Utc>) -> bool {
+        now.naive_utc().date() > self.last_day
+    }
+}
+
+impl<'de> Deserialize<'de> for ExpiryDate {
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// Expiry date, as sent by the API.
+        #[derive(Debug, Deserialize)]
+        struct RawExpiryDate {
+            /// Year of the expiry date.
+            year: i32,
+            /// Month of the expiry date.
+            month: u32,
+        }
+
+        let raw = RawExpiryDate::deserialize(de)?;
+        Self::new(raw.year, raw.month).ok_or_else(|| {
+            de::Error::custom(format!("invalid expiry date: {}/{}", raw.month, raw.year))
+        })
+    }
+}
+
+/// Deserializes an optional string, considering empty strings as absent.
+fn deserialize_non_empty<'de, D>(de: D) -> Result<Option<String>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    use chrono::Duration;
+    Ok(Option::<String>::deserialize(de)?.filter(|value| !value.is_empty()))
+}
 
-    /// Naive year-month representation.
-    #[derive(Debug, Clone, Copy, Deserialize)]
-    struct NaiveYearMonth {
-        year: i32,
-        month: u32,
+/// Deserializes the fee of a card issuer.
+///
+/// The fee is accepted both as a number and as a string, and it's rounded to two decimal digits.
+fn deserialize_fee<'de, D>(de: D) -> Result<Amount, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    /// Representations of a fee.
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    enum Fee {
+        /// Numeric fee.
+        Number(f64),
+        /// Fee in a string.
+        Text(String),
     }
 
-    let mut ym = NaiveYearMonth::deserialize(de)?;
-    // The expiry date is the last day of the month.
-    if ym.month == 12 {
-        ym.year += 1;
-        ym.month = 1;
-    } else {
-        ym.month += 1;
+    match Fee::deserialize(de)? {
+        Fee::Number(fee) => Amount::from_f64(fee)
+            .ok_or_else(|| de::Error::custom(format!("invalid issuer fee: {}", fee))),
+        Fee::Text(fee) => fee.parse().map_err(de::Error::custom),
     }
-    let mut date = NaiveDate::from_ymd(ym.year, ym.month, 1);
-    date -= Duration::days(1);
-
-    Ok(date)
 }
@@ -1,6 +1,141 @@
 //! Public methods of the client.
+//!
+//! The methods in this module call endpoints that don't require authentication, such as the
+//! reference data of the API, so they can be used with a client that never signed in. The methods
+//! in the [`private`](../private/index.html) module require the client to have the authentication
+//! information.
+//!
+//! The sign-in methods, such as [`Client::sign_in()`](../struct.Client.html#method.sign_in) and
+//! [`Client::confirm_sign_in()`](../struct.Client.html#method.confirm_sign_in), are not
+//! authenticated either, but they live in the `private` module, next to the user and wallet types
+//! they return, since they are the entry point to the private API.
 
-use super::Client;
+use anyhow::Error;
+use getset::{CopyGetters, Getters};
+use reqwest::header::ACCEPT;
+use serde::Deserialize;
+
+use super::{handle_error_status, parse_json_list, Client, Country};
 
 /// Public client methods.
-impl Client {}
+///
+/// They don't require the client to have the authentication information.
+impl Client {
+    /// Gets the list of currencies supported by Revolut.
+    ///
+    /// This is reference data, useful to build currency pickers without hardcoding the list of
+    /// currencies.
+    ///
+    /// ## Request API specification
+    ///
+    /// No authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/reference/currencies
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range, and a JSON array with the currencies
+    /// will be returned:
+    ///
+    /// ```json
+    /// [
+    ///     {
+    ///         "code": "EUR",
+    ///         "name": "Euro",
+    ///         "symbol": "€",
+    ///         "crypto": false
+    ///     },
+    ///     {
+    ///         "code": "BTC",
+    ///         "name": "Bitcoin",
+    ///         "symbol": "₿",
+    ///         "crypto": true
+    ///     }
+    /// ]
+    /// ```
+    pub fn reference_currencies(&self) -> Result<Vec<CurrencyInfo>, Error> {
+        self.reference("reference/currencies")
+    }
+
+    /// Gets the list of countries supported by Revolut.
+    ///
+    /// This is reference data, useful to build country pickers without hardcoding the list of
+    /// countries.
+    ///
+    /// ## Request API specification
+    ///
+    /// No authentication required.
+    ///
+    /// ```text
+    /// GET https://api.revolut.com/reference/countries
+    /// ```
+    ///
+    /// The response status code will be in the `2XX` range, and a JSON array with the countries
+    /// will be returned:
+    ///
+    /// ```json
+    /// [
+    ///     {
+    ///         "code": "GB",
+    ///         "name": "United Kingdom",
+    ///         "supported": true
+    ///     }
+    /// ]
+    /// ```
+    pub fn reference_countries(&self) -> Result<Vec<CountryInfo>, Error> {
+        self.reference("reference/countries")
+    }
+
+    /// Gets the reference data in the given endpoint.
+    fn reference<T>(&self, path: &str) -> Result<Vec<T>, Error>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let request_builder = self.client.get(self.endpoint(path));
+
+        let mut response = self.send(
+            self.set_headers(request_builder)
+                .header(ACCEPT, "application/json"),
+        )?;
+
+        if response.status().is_success() {
+            parse_json_list(&mut response)
+        } else {
+            Err(handle_error_status(&mut response))
+        }
+    }
+}
+
+/// Information of a currency supported by Revolut.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+pub struct CurrencyInfo {
+    /// ISO 4217 code of the currency, or the ticker of the cryptocurrency.
+    #[get = "pub"]
+    code: String,
+    /// Name of the currency.
+    #[get = "pub"]
+    name: String,
+    /// Symbol of the currency, if it has one.
+    #[serde(default)]
+    #[get = "pub"]
+    symbol: Option<String>,
+    /// Whether the currency is a cryptocurrency.
+    #[serde(default)]
+    #[get_copy = "pub"]
+    crypto: bool,
+}
+
+/// Information of a country supported by Revolut.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Getters, CopyGetters)]
+pub struct CountryInfo {
+    /// Country.
+    #[get = "pub"]
+    code: Country,
+    /// Name of the country.
+    #[get = "pub"]
+    name: String,
+    /// Whether Revolut accounts can be opened by residents of the country.
+    #[serde(default)]
+    #[get_copy = "pub"]
+    supported: bool,
+}
@@ -0,0 +1,144 @@
+//! Shared client
+//!
+//! This module holds the `SharedClient` type, that allows sharing one logged in client between
+//! threads. The `Client` can already be used from multiple threads for the methods that take
+//! `&self`, by wrapping it in an `Arc`, but the authentication methods need `&mut self`. The
+//! shared client allows calling them through a shared reference, and every clone of the shared
+//! client will see the new authentication information:
+//!
+//! ```
+//! use std::thread;
+//!
+//! use revolut_customer::{Client, SharedClient};
+//!
+//! let shared = SharedClient::new(Client::default());
+//! let handle = {
+//!     let shared = shared.clone();
+//!     thread::spawn(move || {
+//!         shared
+//!             .set_auth("b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d", "some-access-token")
+//!             .unwrap();
+//!     })
+//! };
+//! handle.join().unwrap();
+//!
+//! assert!(shared.read().is_authenticated());
+//! ```
+
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use anyhow::Error;
+
+use crate::{private::ConfirmResult, Client};
+
+/// Client that can be shared between threads.
+///
+/// The API methods are called on the client returned by [`read()`](#method.read), and multiple
+/// threads can call them at the same time. The authentication methods only lock the client to
+/// update the authentication information once the API responded, so the rest of the threads are
+/// not blocked while waiting for the response. If two threads update the authentication at the
+/// same time, the last update wins.
+#[derive(Debug, Clone, Default)]
+pub struct SharedClient {
+    /// Shared client.
+    client: Arc<RwLock<Client>>,
+}
+
+impl SharedClient {
+    /// Creates a new shared client from the given client.
+    pub fn new(client: Client) -> Self {
+        Self {
+            client: Arc::new(RwLock::new(client)),
+        }
+    }
+
+    /// Locks the client for reading.
+    ///
+    /// The lock is shared with the rest of the readers, so it should be used to call the API
+    /// methods.
+    pub fn read(&self) -> RwLockReadGuard<'_, Client> {
+        self.client.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the client for writing.
+    ///
+    /// The rest of the threads will be blocked until the lock is released, so it should only be
+    /// used to change the configuration of the client.
+    pub fn write(&self) -> RwLockWriteGuard<'_, Client> {
+        self.client.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets the authentication information of the client.
+    ///
+    /// See [`Client::set_auth()`](struct.Client.html#method.set_auth).
+    pub fn set_auth<I, T>(&self, user_id: I, access_token: T) -> Result<(), Error>
+    where
+        I: AsRef<str>,
+        T: Into<String>,
+    {
+        self.write().set_auth(user_id, access_token)
+    }
+
+    /// Removes the authentication information of the client.
+    pub fn unset_auth(&self) {
+        self.write().unset_auth();
+    }
+
+    /// Confirms the user sign-in, and stores the received authentication information.
+    ///
+    /// See [`Client::confirm_sign_in()`](struct.Client.html#method.confirm_sign_in).
+    pub fn confirm_sign_in<P, C>(&self, phone: P, code: C) -> Result<ConfirmResult, Error>
+    where
+        P: AsRef<str>,
+        C: AsRef<str>,
+    {
+        self.update_auth(|client| client.confirm_sign_in(phone, code))
+    }
+
+    /// Refreshes the access token of the client.
+    ///
+    /// See [`Client::refresh_token()`](struct.Client.html#method.refresh_token).
+    pub fn refresh_token(&self) -> Result<(), Error> {
+        self.update_auth(Client::refresh_token)
+    }
+
+    /// Signs the user out, and removes the authentication information of the client.
+    ///
+    /// See [`Client::sign_out()`](struct.Client.html#method.sign_out).
+    pub fn sign_out(&self) -> Result<(), Error> {
+        self.update_auth(Client::sign_out)
+    }
+
+    /// Runs an authentication method in a copy of the client, and stores the resulting
+    /// authentication information in the shared client if the method changed it.
+    ///
+    /// The verification passcode is part of the authentication information, so that it's removed
+    /// from the shared client when the user signs out.
+    fn update_auth<F, T>(&self, method: F) -> Result<T, Error>
+    where
+        F: FnOnce(&mut Client) -> Result<T, Error>,
+    {
+        let mut client = self.read().clone();
+        let previous_user_id = client.user_id;
+        let previous_access_token = client.access_token.clone();
+        let previous_verify_password = client.verify_password.clone();
+        let result = method(&mut client);
+
+        if client.user_id != previous_user_id
+            || client.access_token != previous_access_token
+            || client.verify_password != previous_verify_password
+        {
+            let mut shared = self.write();
+            shared.user_id = client.user_id;
+            shared.access_token = client.access_token;
+            shared.verify_password = client.verify_password;
+        }
+        result
+    }
+}
+
+impl From<Client> for SharedClient {
+    fn from(client: Client) -> Self {
+        Self::new(client)
+    }
+}
@@ -0,0 +1,56 @@
+//! HTTP transport
+//!
+//! This module holds the `Transport` trait, used by the client to send the requests to the API.
+//! By default, the requests are sent with the internal `reqwest` client, but a different transport
+//! can be set with [`Client::with_transport()`](../struct.Client.html#method.with_transport), for
+//! example to return scripted responses in tests without performing any network request.
+
+use std::fmt;
+
+use anyhow::Error;
+use reqwest::{Request, Response};
+
+/// HTTP transport used to send the requests to the API.
+///
+/// The requests received by the transport are complete, with all their headers and body, and the
+/// transport must return the response of the server. Since `Response` implements
+/// `From<http::Response<T>>`, responses can be built without a server. Transports can fail with
+/// any error, and the client will report it as an `ApiError::RequestFailure` caused by it:
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// use anyhow::Error;
+/// use reqwest::{Request, Response};
+/// use revolut_customer::{transport::Transport, Client};
+///
+/// /// Transport that always returns a `401 Unauthorized` response.
+/// #[derive(Debug)]
+/// struct Unauthorized {
+///     /// Number of requests sent.
+///     count: Mutex<usize>,
+/// }
+///
+/// impl Transport for Unauthorized {
+///     fn execute(&self, _request: Request) -> Result<Response, Error> {
+///         *self.count.lock().unwrap() += 1;
+///         Ok(http::Response::builder().status(401).body("").unwrap().into())
+///     }
+/// }
+///
+/// let mut client = Client::with_transport(Unauthorized { count: Mutex::new(0) });
+/// client
+///     .set_auth("b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d", "some-access-token")
+///     .unwrap();
+/// assert!(client.current_user_wallet().is_err());
+/// ```
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends the given request, and returns the response of the server.
+    fn execute(&self, request: Request) -> Result<Response, Error>;
+}
+
+impl Transport for reqwest::Client {
+    fn execute(&self, request: Request) -> Result<Response, Error> {
+        Ok(reqwest::Client::execute(self, request)?)
+    }
+}
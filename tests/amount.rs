@@ -1,6 +1,15 @@
 //! Amount representation testing.
 
-use revolut_customer::Amount;
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+};
+
+use revolut_customer::{
+    amount::{ConversionError, Direction, ParseError, RoundingMode},
+    Amount, SignedAmount,
+};
+use serde::{Deserialize, Serialize};
 
 /// Tests that amounts are parsed correctly.
 #[test]
@@ -99,6 +108,70 @@ fn it_amount_bad_format() {
     assert!(amount.is_err());
 }
 
+/// Tests that surrounding whitespace and signs are handled when parsing amounts.
+#[test]
+fn it_amount_parse_sign_whitespace() {
+    let amount = " 12.34 ".parse::<Amount>().unwrap();
+    assert_eq!(amount, Amount::from_repr(12_34));
+
+    let amount = "+5".parse::<Amount>().unwrap();
+    assert_eq!(amount, Amount::from_repr(5_00));
+
+    let amount = "+5.25".parse::<Amount>().unwrap();
+    assert_eq!(amount, Amount::from_repr(5_25));
+
+    let error = "-5".parse::<Amount>().err().unwrap();
+    assert_eq!(
+        error.downcast_ref::<ParseError>().unwrap(),
+        &ParseError::Negative {
+            amount_str: "-5".to_owned()
+        }
+    );
+
+    assert!("++5".parse::<Amount>().is_err());
+    assert!("+-5".parse::<Amount>().is_err());
+    assert!("5.+5".parse::<Amount>().is_err());
+}
+
+/// Tests that amounts are parsed with the given locale.
+#[test]
+fn it_amount_parse_with_locale() {
+    let amount = Amount::parse_with_locale("175,64", ',', None).unwrap();
+    assert_eq!(amount, Amount::from_repr(175_64));
+
+    let amount = Amount::parse_with_locale("£175.64", '.', Some('£')).unwrap();
+    assert_eq!(amount, Amount::from_repr(175_64));
+
+    let amount = Amount::parse_with_locale(" € 0,5 ", ',', Some('€')).unwrap();
+    assert_eq!(amount, Amount::from_repr(0_50));
+
+    let amount = Amount::parse_with_locale("12,345 €", ',', Some('€')).unwrap();
+    assert_eq!(amount, Amount::from_repr(12_35));
+
+    let error = Amount::parse_with_locale("-£5", '.', Some('£'))
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.downcast_ref::<ParseError>().unwrap(),
+        &ParseError::Negative {
+            amount_str: "-£5".to_owned()
+        }
+    );
+
+    let error = Amount::parse_with_locale("1.175,64", ',', None)
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.downcast_ref::<ParseError>().unwrap(),
+        &ParseError::Invalid {
+            amount_str: "1.175,64".to_owned()
+        }
+    );
+
+    assert!(Amount::parse_with_locale("$175.64", '.', Some('£')).is_err());
+    assert!(Amount::parse_with_locale("175,64", '.', None).is_err());
+}
+
 /// Test operations with amounts.
 #[test]
 fn it_amount_ops() {
@@ -127,3 +200,564 @@ fn it_amount_ops() {
     assert_eq!(amount, Amount::from_repr(2_34));
     assert_eq!(amount % 1_u32, Amount::from_repr(0_34));
 }
+
+/// Tests checked and saturating addition of amounts.
+#[test]
+fn it_amount_checked_saturating_add() {
+    let amount = Amount::from_repr(10_00);
+    assert_eq!(
+        amount.checked_add(Amount::from_repr(5_50)),
+        Some(Amount::from_repr(15_50))
+    );
+    assert_eq!(Amount::max_value().checked_add(amount), None);
+
+    assert_eq!(
+        amount.saturating_add(Amount::from_repr(5_50)),
+        Amount::from_repr(15_50)
+    );
+    assert_eq!(
+        Amount::max_value().saturating_add(amount),
+        Amount::max_value()
+    );
+}
+
+/// Tests the formatting of amounts with grouped thousands.
+#[test]
+fn it_amount_format_grouped() {
+    assert_eq!(Amount::zero().format_grouped(',', '.'), "0.00");
+    assert_eq!(Amount::from_repr(5).format_grouped(',', '.'), "0.05");
+    assert_eq!(Amount::from_repr(999_99).format_grouped(',', '.'), "999.99");
+    assert_eq!(
+        Amount::from_repr(1_000_00).format_grouped(',', '.'),
+        "1,000.00"
+    );
+    assert_eq!(
+        Amount::from_repr(12_345_67).format_grouped(',', '.'),
+        "12,345.67"
+    );
+    assert_eq!(
+        Amount::from_repr(123_456_70).format_grouped(',', '.'),
+        "123,456.70"
+    );
+    assert_eq!(
+        Amount::from_repr(1_234_567_89).format_grouped(',', '.'),
+        "1,234,567.89"
+    );
+    assert_eq!(
+        Amount::max_value().format_grouped(',', '.'),
+        "184,467,440,737,095,516.15"
+    );
+
+    let amount = Amount::from_repr(1_234_567_89);
+    assert_eq!(amount.format_grouped('.', ','), "1.234.567,89");
+    assert_eq!(amount.format_grouped(' ', ','), "1 234 567,89");
+    assert_eq!(amount.format_grouped('\'', '.'), "1'234'567.89");
+    assert_eq!(format!("{}", amount), "1234567.89");
+}
+
+/// Tests the split of amounts in equal shares.
+#[test]
+fn it_amount_div_rem() {
+    let cases = [
+        (10_00, 3, 3_33, 1),
+        (7_00, 30, 23, 10),
+        (9_00, 3, 3_00, 0),
+        (1, 2, 0, 1),
+        (0, 5, 0, 0),
+        (12_345_67, 1, 12_345_67, 0),
+        (
+            u64::max_value(),
+            7,
+            u64::max_value() / 7,
+            u64::max_value() % 7,
+        ),
+    ];
+
+    for &(value, divisor, share, remainder) in &cases {
+        let amount = Amount::from_repr(value);
+        let (s, r) = amount.div_rem(divisor);
+        assert_eq!(s, Amount::from_repr(share));
+        assert_eq!(r, Amount::from_repr(remainder));
+        assert!(r.get_repr() < divisor);
+        assert_eq!(
+            (0..divisor).map(|_| s).fold(r, Amount::saturating_add),
+            amount
+        );
+    }
+}
+
+/// Tests that splitting an amount in zero shares panics.
+#[test]
+#[should_panic]
+fn it_amount_div_rem_zero() {
+    let _ = Amount::from_repr(10_00).div_rem(0);
+}
+
+/// Tests the addition and subtraction of whole currency units to amounts.
+#[test]
+fn it_amount_add_sub_units() {
+    let amount = Amount::from_repr(7_34);
+
+    assert_eq!(amount + 5u64, Amount::from_repr(12_34));
+    assert_eq!(amount + Amount::from_repr(5), Amount::from_repr(7_39));
+    assert_ne!(amount + 5u64, amount + Amount::from_repr(5));
+    assert_eq!(amount - 5u8, Amount::from_repr(2_34));
+    assert_eq!(amount - Amount::from_repr(5), Amount::from_repr(7_29));
+
+    let mut amount = amount;
+    amount += 3u16;
+    assert_eq!(amount, Amount::from_repr(10_34));
+    amount -= 10u32;
+    assert_eq!(amount, Amount::from_repr(0_34));
+}
+
+/// Tests the checked addition and subtraction of whole currency units to amounts.
+#[test]
+fn it_amount_checked_add_sub_units() {
+    let amount = Amount::from_repr(7_34);
+
+    assert_eq!(amount.checked_add_units(5), Some(Amount::from_repr(12_34)));
+    assert_eq!(amount.checked_sub_units(7), Some(Amount::from_repr(0_34)));
+    assert_eq!(amount.checked_sub_units(8), None);
+    assert_eq!(Amount::max_value().checked_add_units(1), None);
+    assert_eq!(amount.checked_add_units(u64::max_value()), None);
+    assert_eq!(amount.checked_sub_units(u64::max_value()), None);
+}
+
+/// Tests that subtracting more units than the amount holds panics.
+#[test]
+#[should_panic]
+fn it_amount_sub_units_overflow() {
+    let _ = Amount::from_repr(7_34) - 8u8;
+}
+
+/// Tests the parsing of half cents with each rounding mode.
+#[test]
+fn it_amount_parse_rounding() {
+    let cases = [
+        (RoundingMode::HalfUp, 0_01, 0_02, 0_00),
+        (RoundingMode::HalfEven, 0_00, 0_02, 0_00),
+        (RoundingMode::Floor, 0_00, 0_01, 0_00),
+        (RoundingMode::Ceil, 0_01, 0_02, 0_01),
+    ];
+    for &(rounding, half, one_and_half, below_half) in &cases {
+        assert_eq!(
+            Amount::from_str_with_rounding("0.005", rounding).unwrap(),
+            Amount::from_repr(half),
+            "{:?}",
+            rounding
+        );
+        assert_eq!(
+            Amount::from_str_with_rounding("0.015", rounding).unwrap(),
+            Amount::from_repr(one_and_half),
+            "{:?}",
+            rounding
+        );
+        assert_eq!(
+            Amount::from_str_with_rounding("0.0049", rounding).unwrap(),
+            Amount::from_repr(below_half),
+            "{:?}",
+            rounding
+        );
+        assert_eq!(
+            Amount::from_str_with_rounding("3.50", rounding).unwrap(),
+            Amount::from_repr(3_50)
+        );
+    }
+
+    assert_eq!(RoundingMode::default(), RoundingMode::HalfUp);
+    assert_eq!(
+        "0.005".parse::<Amount>().unwrap(),
+        Amount::from_str_with_rounding("0.005", RoundingMode::default()).unwrap()
+    );
+}
+
+/// Tests that amounts with too many decimal digits to round are rejected instead of overflowing.
+#[test]
+fn it_amount_parse_many_decimals() {
+    assert_eq!(
+        "0.000000000000000000009".parse::<Amount>().unwrap(),
+        Amount::from_repr(0_00)
+    );
+    assert_eq!(
+        "1.005000000000000000000".parse::<Amount>().unwrap(),
+        Amount::from_repr(1_01)
+    );
+
+    assert!("0.0000000000000000000000".parse::<Amount>().is_err());
+    assert!("1.0000000000000000000001".parse::<Amount>().is_err());
+    assert!(
+        Amount::from_str_with_rounding("1.0000000000000000000001", RoundingMode::Floor).is_err()
+    );
+}
+
+/// Tests the application of exchange rates with each rounding mode.
+#[test]
+fn it_amount_apply_rate_rounding() {
+    let cases = [
+        (RoundingMode::HalfUp, 0_01, 0_02),
+        (RoundingMode::HalfEven, 0_00, 0_02),
+        (RoundingMode::Floor, 0_00, 0_01),
+        (RoundingMode::Ceil, 0_01, 0_02),
+    ];
+    for &(rounding, half, one_and_half) in &cases {
+        // 0.01 * 0.5 = 0.005
+        assert_eq!(
+            Amount::from_repr(0_01)
+                .apply_rate_with_rounding(0.5, rounding)
+                .unwrap(),
+            Amount::from_repr(half),
+            "{:?}",
+            rounding
+        );
+        // 0.03 * 0.5 = 0.015
+        assert_eq!(
+            Amount::from_repr(0_03)
+                .apply_rate_with_rounding(0.5, rounding)
+                .unwrap(),
+            Amount::from_repr(one_and_half),
+            "{:?}",
+            rounding
+        );
+    }
+
+    assert_eq!(
+        Amount::from_repr(0_01).apply_rate(0.5).unwrap(),
+        Amount::from_repr(0_01)
+    );
+    assert!(Amount::from_repr(0_01)
+        .apply_rate_with_rounding(-0.5, RoundingMode::Floor)
+        .is_err());
+}
+
+/// Tests the checked remainder of amounts.
+#[test]
+fn it_amount_checked_rem() {
+    let amount = Amount::from_repr(7_34);
+
+    assert_eq!(amount.checked_rem(0), None);
+    assert_eq!(amount.checked_rem(1), Some(Amount::from_repr(0_34)));
+    assert_eq!(amount.checked_rem(5), Some(Amount::from_repr(2_34)));
+    assert_eq!(amount.checked_rem(10), Some(amount));
+    assert_eq!(amount.checked_rem(u64::max_value()), Some(amount));
+}
+
+/// Tests that the remainder of amounts is computed in whole currency units.
+#[test]
+fn it_amount_rem_semantics() {
+    let mut amount = Amount::from_repr(7_34);
+    assert_eq!(amount % 2u8, Amount::from_repr(1_34));
+    assert_eq!(amount % 5u64, amount.checked_rem(5).unwrap());
+
+    amount %= 1u8;
+    assert_eq!(amount, Amount::from_repr(0_34));
+    assert_eq!(amount.to_string(), "0.34");
+}
+
+/// Tests that the remainder of an amount by zero panics.
+#[test]
+#[should_panic]
+fn it_amount_rem_zero() {
+    let _ = Amount::from_repr(7_34) % 0u8;
+}
+
+/// Tests the minimum, maximum and clamping of amounts.
+#[test]
+fn it_amount_min_max_clamp() {
+    let small = Amount::from_repr(5_00);
+    let big = Amount::from_repr(20_00);
+    assert_eq!(small.min(big), small);
+    assert_eq!(big.min(small), small);
+    assert_eq!(small.max(big), big);
+    assert_eq!(big.max(small), big);
+    assert_eq!(small.min(small), small);
+
+    let lo = Amount::from_repr(10_00);
+    let hi = Amount::from_repr(15_00);
+    assert_eq!(small.clamp(lo, hi), lo);
+    assert_eq!(big.clamp(lo, hi), hi);
+    assert_eq!(
+        Amount::from_repr(12_34).clamp(lo, hi),
+        Amount::from_repr(12_34)
+    );
+    assert_eq!(lo.clamp(lo, hi), lo);
+    assert_eq!(hi.clamp(lo, hi), hi);
+    assert_eq!(small.clamp(lo, lo), lo);
+    assert_eq!(Amount::zero().clamp(Amount::zero(), hi), Amount::zero());
+    assert_eq!(
+        Amount::max_value().clamp(lo, Amount::max_value()),
+        Amount::max_value()
+    );
+}
+
+/// Tests that clamping an amount panics if the bounds are inverted.
+#[test]
+#[should_panic]
+fn it_amount_clamp_inverted_bounds() {
+    let _ = Amount::from_repr(12_00).clamp(Amount::from_repr(15_00), Amount::from_repr(10_00));
+}
+
+/// Tests the creation of amounts from major and minor units, and their split.
+#[test]
+fn it_amount_major_minor() {
+    let amount = Amount::from_major_minor(1, 65).unwrap();
+    assert_eq!(amount, Amount::from_repr(1_65));
+    assert_eq!(Amount::from_major_minor(0, 0).unwrap(), Amount::min_value());
+
+    let error = Amount::from_major_minor(1, 100).err().unwrap();
+    assert_eq!(
+        error.downcast_ref::<ConversionError>().unwrap(),
+        &ConversionError::InvalidMinor { minor: 100 }
+    );
+
+    let error = Amount::from_major_minor(u64::max_value(), 0).err().unwrap();
+    assert_eq!(
+        error.downcast_ref::<ConversionError>().unwrap(),
+        &ConversionError::Overflow
+    );
+
+    assert_eq!(Amount::from_repr(11_65).split(), (11, 65));
+    assert_eq!(Amount::from_repr(0_05).split(), (0, 5));
+
+    let (major, minor) = Amount::max_value().split();
+    assert_eq!(
+        Amount::from_major_minor(major, minor).unwrap(),
+        Amount::max_value()
+    );
+}
+
+/// Tests the multiplication of amounts by floating point factors.
+#[test]
+fn it_amount_mul_f64() {
+    assert_eq!(
+        Amount::from_repr(100_00).apply_rate(1.17).unwrap(),
+        Amount::from_repr(117_00)
+    );
+    assert_eq!(
+        Amount::from_repr(10_00).mul_f64(0.333).unwrap(),
+        Amount::from_repr(3_33)
+    );
+    assert_eq!(
+        Amount::from_repr(1_00).mul_f64(0.125).unwrap(),
+        Amount::from_repr(0_13)
+    );
+    assert_eq!(
+        Amount::from_repr(1_00).mul_f64(0.0).unwrap(),
+        Amount::min_value()
+    );
+
+    for &factor in &[-1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let error = Amount::from_repr(10_00).mul_f64(factor).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ConversionError>().unwrap(),
+            ConversionError::InvalidFactor { .. }
+        ));
+    }
+
+    let error = Amount::max_value().mul_f64(2.0).err().unwrap();
+    assert_eq!(
+        error.downcast_ref::<ConversionError>().unwrap(),
+        &ConversionError::Overflow
+    );
+}
+
+/// Tests the creation of amounts from floating point numbers.
+#[test]
+fn it_amount_from_f64() {
+    assert_eq!(Amount::from_f64(1.65), Some(Amount::from_repr(1_65)));
+    assert_eq!(Amount::from_f64(0.005), Some(Amount::from_repr(0_01)));
+    assert_eq!(Amount::from_f64(0.004), Some(Amount::from_repr(0)));
+    assert_eq!(Amount::from_f64(175.64), Some(Amount::from_repr(175_64)));
+    assert_eq!(Amount::from_f64(0.0), Some(Amount::zero()));
+
+    for &value in &[-1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e18] {
+        assert_eq!(Amount::from_f64(value), None);
+    }
+}
+
+/// Tests the conversions between amounts and floating point numbers.
+#[test]
+fn it_amount_f64_conversions() {
+    assert!((f64::from(Amount::from_repr(175_64)) - 175.64).abs() < f64::EPSILON * 1_000.0);
+    assert!(f64::from(Amount::zero()).abs() < f64::EPSILON);
+    assert!((f64::from(Amount::from_repr(0_05)) - 0.05).abs() < f64::EPSILON);
+
+    assert_eq!(Amount::try_from(175.64).unwrap(), Amount::from_repr(175_64));
+    assert_eq!(
+        Amount::try_from(f64::from(Amount::from_repr(1_65))).unwrap(),
+        Amount::from_repr(1_65)
+    );
+
+    for &value in &[-1.0, f64::NAN, f64::INFINITY] {
+        let error = Amount::try_from(value).err().unwrap();
+        assert!(matches!(
+            error.downcast_ref::<ConversionError>().unwrap(),
+            ConversionError::InvalidValue { .. }
+        ));
+    }
+    let error = Amount::try_from(1e18).err().unwrap();
+    assert_eq!(
+        error.downcast_ref::<ConversionError>().unwrap(),
+        &ConversionError::Overflow
+    );
+}
+
+/// Tests the serialization of amounts as decimal strings.
+#[test]
+fn it_amount_decimal_string() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Export {
+        #[serde(with = "revolut_customer::amount::as_decimal_string")]
+        amount: Amount,
+    }
+
+    let export = Export {
+        amount: Amount::from_repr(175_64),
+    };
+    let json = serde_json::to_string(&export).unwrap();
+    assert_eq!(json, r#"{"amount":"175.64"}"#);
+    assert_eq!(serde_json::from_str::<Export>(&json).unwrap(), export);
+
+    let export = Export {
+        amount: Amount::from_repr(56_00),
+    };
+    let json = serde_json::to_string(&export).unwrap();
+    assert_eq!(json, r#"{"amount":"56.00"}"#);
+    assert_eq!(serde_json::from_str::<Export>(&json).unwrap(), export);
+
+    let export: Export = serde_json::from_str(r#"{"amount":"1.005"}"#).unwrap();
+    assert_eq!(export.amount, Amount::from_repr(1_01));
+    assert_eq!(
+        serde_json::to_string(&export).unwrap(),
+        r#"{"amount":"1.01"}"#
+    );
+
+    assert!(serde_json::from_str::<Export>(r#"{"amount":"-1.00"}"#).is_err());
+    assert!(serde_json::from_str::<Export>(r#"{"amount":175}"#).is_err());
+
+    // The default serialization is still the internal representation.
+    assert_eq!(
+        serde_json::to_string(&Amount::from_repr(175_64)).unwrap(),
+        "17564"
+    );
+}
+
+/// Tests the sum of amounts.
+#[test]
+fn it_amount_sum() {
+    let empty: Vec<Amount> = Vec::new();
+    assert_eq!(empty.iter().sum::<Amount>(), Amount::min_value());
+    assert_eq!(empty.into_iter().sum::<Amount>(), Amount::min_value());
+
+    let amounts = vec![
+        Amount::from_repr(1_50),
+        Amount::from_repr(2_25),
+        Amount::from_repr(10_00),
+    ];
+    assert_eq!(amounts.iter().sum::<Amount>(), Amount::from_repr(13_75));
+    assert_eq!(
+        amounts.into_iter().sum::<Amount>(),
+        Amount::from_repr(13_75)
+    );
+
+    let amounts = [Amount::max_value(), Amount::from_repr(1)];
+    assert_eq!(amounts.iter().sum::<Amount>(), Amount::max_value());
+}
+
+/// Tests the zero amount.
+#[test]
+fn it_amount_zero() {
+    assert_eq!(Amount::zero(), Amount::from_repr(0));
+    assert_eq!(Amount::zero(), Amount::min_value());
+    assert!(Amount::zero().is_zero());
+    assert!("0.00".parse::<Amount>().unwrap().is_zero());
+    assert!(!Amount::from_repr(1).is_zero());
+    assert!((Amount::from_repr(1_50) - Amount::from_repr(1_50)).is_zero());
+}
+
+/// Tests the formatting of signed amounts.
+#[test]
+fn it_signed_amount_display() {
+    let debit = SignedAmount::debit(Amount::from_repr(25_50));
+    assert_eq!(format!("{}", debit), "-25.5");
+    assert_eq!(format!("{:.2}", debit), "-25.50");
+    assert_eq!(format!("{:08.2}", debit), "-0025.50");
+
+    let credit = SignedAmount::credit(Amount::from_repr(10_00));
+    assert_eq!(format!("{}", credit), "10");
+    assert_eq!(format!("{:+.2}", credit), "+10.00");
+
+    let zero = SignedAmount::debit(Amount::zero());
+    assert!(zero.is_credit());
+    assert_eq!(format!("{}", zero), "0");
+}
+
+/// Tests the arithmetic of signed amounts across directions.
+#[test]
+fn it_signed_amount_add() {
+    let debit = SignedAmount::debit(Amount::from_repr(25_50));
+    let credit = SignedAmount::credit(Amount::from_repr(10_00));
+
+    assert_eq!(
+        debit + credit,
+        SignedAmount::debit(Amount::from_repr(15_50))
+    );
+    assert_eq!(
+        credit + debit,
+        SignedAmount::debit(Amount::from_repr(15_50))
+    );
+    assert_eq!(debit + debit, SignedAmount::debit(Amount::from_repr(51_00)));
+    assert_eq!(
+        credit - debit,
+        SignedAmount::credit(Amount::from_repr(35_50))
+    );
+    assert_eq!(-credit, SignedAmount::debit(Amount::from_repr(10_00)));
+    assert_eq!(debit - debit, SignedAmount::credit(Amount::zero()));
+
+    let mut balance = SignedAmount::from(Amount::from_repr(5_00));
+    balance += debit;
+    assert_eq!(balance.direction(), Direction::Debit);
+    balance -= debit;
+    assert_eq!(balance, SignedAmount::credit(Amount::from_repr(5_00)));
+}
+
+/// Tests the serialization of signed amounts as their signed internal representation.
+#[test]
+fn it_signed_amount_serde() {
+    let debit = SignedAmount::from_repr(-25_50);
+    assert_eq!(debit, SignedAmount::debit(Amount::from_repr(25_50)));
+    assert_eq!(debit.get_repr(), Some(-25_50));
+    assert_eq!(serde_json::to_string(&debit).unwrap(), "-2550");
+    assert_eq!(
+        serde_json::from_str::<SignedAmount>("-2550").unwrap(),
+        debit
+    );
+
+    let min = SignedAmount::from_repr(i64::min_value());
+    assert!(min.is_debit());
+    assert_eq!(min.get_repr(), Some(i64::min_value()));
+    assert_eq!(SignedAmount::credit(Amount::max_value()).get_repr(), None);
+    assert!(serde_json::to_string(&SignedAmount::credit(Amount::max_value())).is_err());
+}
+
+/// Tests the use of amounts as keys of hash sets and maps.
+#[test]
+fn it_amount_hash() {
+    let mut amounts = HashSet::new();
+    assert!(amounts.insert(Amount::from_repr(10_00)));
+    assert!(amounts.insert(Amount::from_repr(25_50)));
+    assert!(!amounts.insert("10".parse().unwrap()));
+    assert_eq!(amounts.len(), 2);
+    assert!(amounts.contains(&Amount::from_repr(25_50)));
+
+    let mut counts = HashMap::new();
+    for amount in &[
+        SignedAmount::debit(Amount::from_repr(3_50)),
+        SignedAmount::credit(Amount::from_repr(3_50)),
+        SignedAmount::from_repr(-3_50),
+    ] {
+        *counts.entry(*amount).or_insert(0) += 1;
+    }
+    assert_eq!(counts[&SignedAmount::debit(Amount::from_repr(3_50))], 2);
+    assert_eq!(counts[&SignedAmount::credit(Amount::from_repr(3_50))], 1);
+}
@@ -0,0 +1,37 @@
+//! Country representation testing.
+
+use revolut_customer::Country;
+
+/// Tests the conversion of known country codes.
+#[test]
+fn it_country_known_codes() {
+    assert_eq!(Country::from("FR"), Country::France);
+    assert_eq!(Country::from("GB"), Country::UnitedKingdom);
+    assert_eq!(Country::from("US"), Country::UnitedStates);
+    assert_eq!(Country::from("es"), Country::Spain);
+    assert_eq!(Country::from("De".to_owned()), Country::Germany);
+
+    assert_eq!(Country::Lithuania.as_str(), "LT");
+    assert_eq!(format!("{}", Country::Netherlands), "NL");
+}
+
+/// Tests that unknown country codes are kept.
+#[test]
+fn it_country_unknown_code() {
+    let country = Country::from("XK");
+    assert_eq!(country, Country::Unknown("XK".to_owned()));
+    assert_eq!(country.as_str(), "XK");
+    assert_eq!(format!("{}", country), "XK");
+}
+
+/// Tests the serialization and deserialization of countries.
+#[test]
+fn it_country_serde() {
+    let country: Country = serde_json::from_str("\"PT\"").unwrap();
+    assert_eq!(country, Country::Portugal);
+    assert_eq!(serde_json::to_string(&country).unwrap(), "\"PT\"");
+
+    let country: Country = serde_json::from_str("\"ZZ\"").unwrap();
+    assert_eq!(country, Country::Unknown("ZZ".to_owned()));
+    assert_eq!(serde_json::to_string(&country).unwrap(), "\"ZZ\"");
+}
@@ -0,0 +1,44 @@
+//! Email address representation testing.
+
+use revolut_customer::{email::ParseError, Email};
+
+/// Tests that valid email addresses are parsed.
+#[test]
+fn it_email_parse() {
+    let email = "john.doe@example.com".parse::<Email>().unwrap();
+    assert_eq!(email.as_str(), "john.doe@example.com");
+    assert!(email.is_valid());
+    assert_eq!(email.local_part(), Some("john.doe"));
+    assert_eq!(email.domain(), Some("example.com"));
+    assert_eq!(format!("{}", email), "john.doe@example.com");
+
+    let email = "a@b".parse::<Email>().unwrap();
+    assert_eq!(email.local_part(), Some("a"));
+    assert_eq!(email.domain(), Some("b"));
+}
+
+/// Tests that invalid email addresses are rejected.
+#[test]
+fn it_email_parse_invalid() {
+    for email_str in &["", "@", "john.doe", "@example.com", "john.doe@", "a@b@c"] {
+        let err = email_str.parse::<Email>().unwrap_err();
+        assert!(
+            err.downcast_ref::<ParseError>().is_some(),
+            "{} should not be valid",
+            email_str
+        );
+    }
+}
+
+/// Tests that email addresses coming from the API are deserialized even if they are not valid.
+#[test]
+fn it_email_deserialize_lenient() {
+    let email: Email = serde_json::from_str("\"john.doe@example.com\"").unwrap();
+    assert_eq!(email, "john.doe@example.com".parse().unwrap());
+
+    let email: Email = serde_json::from_str("\"john.doe\"").unwrap();
+    assert_eq!(email.as_str(), "john.doe");
+    assert!(!email.is_valid());
+    assert_eq!(email.local_part(), None);
+    assert_eq!(email.domain(), None);
+}
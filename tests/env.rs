@@ -0,0 +1,64 @@
+//! Environment configuration tests.
+//!
+//! They are in their own test binary, since they change the environment of the process, and the
+//! tests of the other binaries read it while running in parallel.
+
+#![cfg(feature = "env")]
+
+use std::env;
+
+use revolut_customer::{ApiError, Client};
+
+/// User ID used in the tests.
+const MOCK_USER_ID: &str = "b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d";
+/// Access token used in the tests.
+const MOCK_ACCESS_TOKEN: &str = "mock-access-token";
+
+/// Tests the creation of clients from the environment variables.
+#[test]
+fn it_client_from_env() {
+    env::remove_var("REVOLUT_USER_ID");
+    env::remove_var("REVOLUT_ACCESS_TOKEN");
+    let error = Client::from_env().unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ApiError>(),
+        Some(&ApiError::MissingEnvVars {
+            vars: vec!["REVOLUT_USER_ID", "REVOLUT_ACCESS_TOKEN"],
+        })
+    );
+
+    env::set_var("REVOLUT_USER_ID", MOCK_USER_ID);
+    let error = Client::from_env().unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ApiError>(),
+        Some(&ApiError::MissingEnvVars {
+            vars: vec!["REVOLUT_ACCESS_TOKEN"],
+        })
+    );
+
+    env::set_var("REVOLUT_ACCESS_TOKEN", MOCK_ACCESS_TOKEN);
+    env::set_var("REVOLUT_CONNECT_TIMEOUT_MS", "soon");
+    let error = Client::from_env().unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ApiError>(),
+        Some(&ApiError::InvalidEnvVar {
+            name: "REVOLUT_CONNECT_TIMEOUT_MS",
+        })
+    );
+
+    env::set_var("REVOLUT_CONNECT_TIMEOUT_MS", "1500");
+    env::set_var("REVOLUT_DEVICE_ID", "MY-DEVICE-ID");
+    let client = Client::from_env().unwrap();
+    assert!(client.is_authenticated());
+    assert_eq!(client.user_id(), Some(MOCK_USER_ID.parse().unwrap()));
+    assert_eq!(client.access_token().unwrap(), MOCK_ACCESS_TOKEN);
+
+    for var in &[
+        "REVOLUT_USER_ID",
+        "REVOLUT_ACCESS_TOKEN",
+        "REVOLUT_CONNECT_TIMEOUT_MS",
+        "REVOLUT_DEVICE_ID",
+    ] {
+        env::remove_var(var);
+    }
+}
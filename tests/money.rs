@@ -0,0 +1,68 @@
+//! Money representation testing.
+
+use revolut_customer::{
+    money::{MoneyLocale, SymbolPosition},
+    Amount, Money,
+};
+
+/// Tests that money is formatted correctly in different locales.
+#[test]
+fn it_money_format_locale() {
+    let us = MoneyLocale::new('.', ',', "€", SymbolPosition::Before);
+    let german = MoneyLocale::new(',', '.', " €", SymbolPosition::After);
+
+    let money = Money::new(Amount::from_repr(1_234_56), "EUR");
+    assert_eq!(money.format_locale(&us), "€1,234.56");
+    assert_eq!(money.format_locale(&german), "1.234,56 €");
+
+    let money = Money::new(Amount::from_repr(1_234_567_89), "EUR");
+    assert_eq!(money.format_locale(&us), "€1,234,567.89");
+    assert_eq!(money.format_locale(&german), "1.234.567,89 €");
+
+    let money = Money::new(Amount::from_repr(123_40), "EUR");
+    assert_eq!(money.format_locale(&us), "€123.40");
+    assert_eq!(money.format_locale(&german), "123,40 €");
+
+    let money = Money::new(Amount::from_repr(0_05), "EUR");
+    assert_eq!(money.format_locale(&us), "€0.05");
+    assert_eq!(money.format_locale(&german), "0,05 €");
+}
+
+/// Tests that money is displayed correctly.
+#[test]
+fn it_money_display() {
+    let money = Money::new(Amount::from_repr(175_64), "GBP");
+    assert_eq!(format!("{}", money), "175.64 GBP");
+}
+
+/// Tests that money is only added in the same currency.
+#[test]
+fn it_money_checked_add() {
+    let eur = Money::new(Amount::from_repr(10_50), "EUR");
+
+    assert_eq!(
+        eur.checked_add(&Money::new(Amount::from_repr(2_25), "EUR")),
+        Some(Money::new(Amount::from_repr(12_75), "EUR"))
+    );
+    assert_eq!(
+        eur.checked_add(&Money::new(Amount::max_value(), "EUR")),
+        None
+    );
+    assert_eq!(
+        eur.checked_add(&Money::new(Amount::from_repr(2_25), "GBP")),
+        None
+    );
+}
+
+/// Tests that money is only compared in the same currency.
+#[test]
+fn it_money_compare() {
+    let eur = Money::new(Amount::from_repr(10_50), "EUR");
+    let more_eur = Money::new(Amount::from_repr(20_00), "EUR");
+    let gbp = Money::new(Amount::from_repr(5_00), "GBP");
+
+    assert!(eur < more_eur);
+    assert!(more_eur > eur);
+    assert_eq!(eur.partial_cmp(&gbp), None);
+    assert_ne!(eur, gbp);
+}
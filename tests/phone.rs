@@ -0,0 +1,48 @@
+//! Phone number representation testing.
+
+use revolut_customer::{phone::ParseError, Phone};
+
+/// Tests that valid E.164 phone numbers are parsed.
+#[test]
+fn it_phone_parse() {
+    let phone = "+1555555555".parse::<Phone>().unwrap();
+    assert_eq!(phone.as_str(), "+1555555555");
+    assert!(phone.is_valid());
+    assert_eq!(format!("{}", phone), "+1555555555");
+
+    assert!("+6834002".parse::<Phone>().is_ok());
+    assert!("+123456789012345".parse::<Phone>().is_ok());
+}
+
+/// Tests that invalid phone numbers are rejected.
+#[test]
+fn it_phone_parse_invalid() {
+    for phone_str in &[
+        "",
+        "1555555555",
+        "+",
+        "+155555",
+        "+1234567890123456",
+        "+0155555555",
+        "+1 555 555 555",
+        "+1555-555-555",
+    ] {
+        let err = phone_str.parse::<Phone>().unwrap_err();
+        assert!(
+            err.downcast_ref::<ParseError>().is_some(),
+            "{} should not be valid",
+            phone_str
+        );
+    }
+}
+
+/// Tests that phone numbers coming from the API are deserialized even if they are not valid.
+#[test]
+fn it_phone_deserialize_lenient() {
+    let phone: Phone = serde_json::from_str("\"+1555555555\"").unwrap();
+    assert_eq!(phone, "+1555555555".parse().unwrap());
+
+    let phone: Phone = serde_json::from_str("\"(555) 555-555\"").unwrap();
+    assert_eq!(phone.as_str(), "(555) 555-555");
+    assert!(!phone.is_valid());
+}
@@ -0,0 +1,26 @@
+//! Prelude testing.
+
+use revolut_customer::prelude::*;
+
+/// Tests that the common types can be used through the prelude.
+#[test]
+fn it_prelude() {
+    let options = OptionsBuilder::default()
+        .client_version("5.29.1")
+        .build()
+        .unwrap();
+    let client = Client::with_options(options);
+    assert!(!client.is_authenticated());
+
+    let error = client.current_user().unwrap_err();
+    assert_eq!(error.as_api_error(), Some(&ApiError::NotLoggedIn));
+
+    let amount: Amount = "12.50".parse().unwrap();
+    assert_eq!(amount, Amount::from_repr(1_250));
+
+    let _: Option<&Address> = None;
+    let _: Option<&Card> = None;
+    let _: Option<&User> = None;
+    let _: Option<&Wallet> = None;
+    let _: Option<&Options> = None;
+}
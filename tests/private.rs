@@ -1,8 +1,496 @@
 //! Private API methods tests.
 
-use std::env;
+use std::{
+    collections::VecDeque,
+    env, fs,
+    io::Write,
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
 
-use revolut_customer::{private::Address, ApiError, Client};
+use chrono::{NaiveDate, TimeZone, Utc};
+use flate2::{write::GzEncoder, Compression};
+use mockito::{Matcher, Server};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, USER_AGENT},
+    Proxy, Request, Response, StatusCode, Url,
+};
+use revolut_customer::{
+    private::{
+        Address, AddressUpdate, AutoTopupStatus, Card, CardBrand, CardType, CardTypeParseError,
+        ConfirmResult, ExpiryDate, PocketState, PocketType, StatementFormat, Transaction, User,
+        UserState, VerificationType, Wallet, WalletState,
+    },
+    Amount, ApiError, ApiErrorExt, Client, Country, Money, Options, OptionsBuilder, Platform,
+    SharedClient, SignedAmount, Transport,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+/// User ID used in the tests against the mock server.
+const MOCK_USER_ID: &str = "b2c3f0a1-7d9e-4a5b-8c6d-1e2f3a4b5c6d";
+/// Access token used in the tests against the mock server.
+const MOCK_ACCESS_TOKEN: &str = "mock-access-token";
+
+/// Gets the JSON representation of a wallet with EUR and GBP pockets.
+fn wallet_json() -> serde_json::Value {
+    json!({
+        "id": "9f8e7d6c-5b4a-4392-8170-6f5e4d3c2b1a",
+        "ref": "1234567890",
+        "state": "ACTIVE",
+        "baseCurrency": "EUR",
+        "totalTopup": 100_00,
+        "topupResetDate": 1_546_300_800_000_i64,
+        "pockets": [
+            {
+                "id": "1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+                "type": "CURRENT",
+                "state": "ACTIVE",
+                "currency": "EUR",
+                "balance": 150_25,
+                "blockedAmount": 0,
+                "closed": false,
+                "creditLimit": 0,
+            },
+            {
+                "id": "2b3c4d5e-6f7a-4b8c-9d0e-1f2a3b4c5d6e",
+                "type": "SAVINGS",
+                "state": "ACTIVE",
+                "currency": "EUR",
+                "balance": 20_00,
+                "blockedAmount": 0,
+                "closed": false,
+                "creditLimit": 0,
+            },
+            {
+                "id": "3c4d5e6f-7a8b-4c9d-0e1f-2a3b4c5d6e7f",
+                "type": "CURRENT",
+                "state": "ACTIVE",
+                "currency": "GBP",
+                "balance": 30_50,
+                "blockedAmount": 0,
+                "closed": false,
+                "creditLimit": 0,
+            },
+            {
+                "id": "4d5e6f7a-8b9c-4d0e-1f2a-3b4c5d6e7f8a",
+                "type": "CURRENT",
+                "state": "CLOSED",
+                "currency": "USD",
+                "balance": 99_00,
+                "blockedAmount": 0,
+                "closed": true,
+                "creditLimit": 0,
+            },
+        ],
+    })
+}
+
+/// Gets the JSON representation of the current user, with the given phone and email.
+fn user_json(phone: &str, email: &str) -> serde_json::Value {
+    json!({
+        "id": MOCK_USER_ID,
+        "createdDate": 1_546_300_800_000_i64,
+        "address": {
+            "city": "New City",
+            "country": "FR",
+            "postcode": "39325",
+            "region": "NewRegion",
+            "streetLine1": "Street 1, 6",
+            "streetLine2": "Apt. 5"
+        },
+        "birthDate": [1990, 1, 31],
+        "firstName": "John",
+        "lastName": "Doe",
+        "phone": phone,
+        "email": email,
+        "emailVerified": false,
+        "state": "ACTIVE",
+        "referralCode": "johnd1",
+        "kyc": "PASSED",
+        "termsVersion": "2018-05-25",
+        "underReview": false,
+        "riskAssessed": false,
+        "locale": "en-GB",
+        "sof": { "state": "PASSED" }
+    })
+}
+
+/// Gets the JSON representation of a card with the given ID.
+fn card_json(card_id: &str) -> serde_json::Value {
+    json!({
+        "id": card_id,
+        "ownerId": MOCK_USER_ID,
+        "lastFour": "1234",
+        "brand": "VISA",
+        "expiryDate": { "year": 2030, "month": 12 },
+        "expired": false,
+        "threeDVerified": true,
+        "address": {
+            "city": "New City",
+            "country": "FR",
+            "postcode": "39325",
+            "region": "NewRegion",
+            "streetLine1": "Street 1, 6",
+            "streetLine2": "Apt. 5"
+        },
+        "postcode": "39325",
+        "issuer": {
+            "bin": "412345",
+            "name": "Some Bank",
+            "cardType": "DEBIT",
+            "cardBrand": "VISA",
+            "country": "FR",
+            "currency": "EUR",
+            "supported": true,
+            "fee": 0.0,
+            "postcodeRequired": false
+        },
+        "currency": "EUR",
+        "confirmed": true,
+        "confirmationAttempts": 1,
+        "autoTopup": "DISABLED",
+        "autoTopupReason": "",
+        "createdDate": 1_546_300_800_000_i64,
+        "updatedDate": 1_546_300_800_000_i64,
+        "associatedBankType": "OTHER",
+        "lastUsedDate": 1_546_300_800_000_i64,
+        "currentTopup": 0,
+        "creditRepayment": false
+    })
+}
+
+/// Creates an authenticated client pointing to the given mock server.
+fn mock_client(server: &Server) -> Client {
+    let mut client = Client::default();
+    client
+        .set_base_url(Url::parse(&server.url()).expect("invalid mock server URL"))
+        .unwrap();
+    client
+        .set_auth(MOCK_USER_ID, MOCK_ACCESS_TOKEN)
+        .expect("invalid user ID");
+    client
+}
+
+/// Tests the generation of random device IDs.
+#[test]
+fn it_options_generated_device_id() {
+    let options = Options::default().with_generated_device_id();
+    assert_ne!(options.device_id(), Options::default().device_id());
+    assert!(options.device_id().parse::<Uuid>().is_ok());
+    assert_eq!(options.device_id(), &options.device_id().to_uppercase());
+
+    let other = Options::default().with_generated_device_id();
+    assert_ne!(options.device_id(), other.device_id());
+}
+
+/// Tests that the persisted device ID is reused across runs.
+#[test]
+fn it_options_persisted_device_id() {
+    let path = env::temp_dir().join(format!("revolut_customer_device_id_{}", Uuid::new_v4()));
+
+    let first = Options::default().with_persisted_device_id(&path).unwrap();
+    let second = Options::default().with_persisted_device_id(&path).unwrap();
+    assert_eq!(first.device_id(), second.device_id());
+    assert_ne!(first.device_id(), Options::default().device_id());
+    assert_eq!(fs::read_to_string(&path).unwrap().trim(), first.device_id());
+
+    fs::write(&path, "MY-DEVICE-ID\n").unwrap();
+    let custom = Options::default().with_persisted_device_id(&path).unwrap();
+    assert_eq!(custom.device_id(), "MY-DEVICE-ID");
+
+    fs::remove_file(&path).unwrap();
+}
+
+/// Tests the validation of the versions in the options builder.
+#[test]
+fn it_options_builder_validation() {
+    let options = OptionsBuilder::default()
+        .client_version("5.29.1")
+        .api_version("1")
+        .build()
+        .unwrap();
+    assert_eq!(options.client_version(), "5.29.1");
+
+    for client_version in &["5", "5.", "5..29", "v5.29", "Revolut/5.29"] {
+        let error = OptionsBuilder::default()
+            .client_version(*client_version)
+            .build()
+            .err()
+            .unwrap();
+        assert!(error.contains("client version"), "{}", error);
+    }
+
+    let error = OptionsBuilder::default()
+        .api_version("v1")
+        .build()
+        .err()
+        .unwrap();
+    assert!(error.contains("API version"), "{}", error);
+}
+
+/// Tests the typed access to the API version of the options.
+#[test]
+fn it_options_api_version_num() {
+    assert_eq!(Options::iphone().api_version_num().unwrap(), 1);
+    assert_eq!(Options::android().api_version_num().unwrap(), 1);
+
+    let options = OptionsBuilder::default()
+        .api_version_num(3)
+        .build()
+        .unwrap();
+    assert_eq!(options.api_version(), "3");
+    assert_eq!(options.api_version_num().unwrap(), 3);
+
+    let user_agent = Options::default().user_agent().clone();
+    for api_version in &[
+        "",
+        "v1",
+        "1.0",
+        "+1",
+        "-1",
+        "99999999999",
+        user_agent.as_str(),
+    ] {
+        let error = OptionsBuilder::default()
+            .api_version(*api_version)
+            .build()
+            .err()
+            .unwrap();
+        assert!(error.contains("API version"), "{}", error);
+    }
+}
+
+/// Tests the serialization and deserialization of the options.
+#[test]
+fn it_options_serde() {
+    for options in &[Options::iphone(), Options::android()] {
+        let json = serde_json::to_string(options).unwrap();
+        assert_eq!(&serde_json::from_str::<Options>(&json).unwrap(), options);
+    }
+
+    let options = OptionsBuilder::default()
+        .device_id("MY-DEVICE-ID")
+        .locale("es-ES".to_owned())
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap();
+    let value = serde_json::to_value(&options).unwrap();
+    assert_eq!(value["device_id"], json!("MY-DEVICE-ID"));
+    assert_eq!(value["api_version"], json!("1"));
+    assert_eq!(value["locale"], json!("es-ES"));
+    assert_eq!(serde_json::from_value::<Options>(value).unwrap(), options);
+
+    let options: Options = serde_json::from_value(json!({ "device_id": "MY-DEVICE-ID" })).unwrap();
+    assert_eq!(options.device_id(), "MY-DEVICE-ID");
+    assert_eq!(options.user_agent(), Options::default().user_agent());
+    assert_eq!(options.timeout(), None);
+
+    let error = serde_json::from_value::<Options>(json!({ "client_version": "latest" }))
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("client version"), "{}", error);
+    let error = serde_json::from_value::<Options>(json!({ "api_version": "+1" }))
+        .err()
+        .unwrap();
+    assert!(error.to_string().contains("API version"), "{}", error);
+}
+
+/// Tests that the locale of the options is sent in the `Accept-Language` header.
+#[test]
+fn it_client_accept_language() {
+    let mut server = Server::new();
+    let without_locale = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("accept-language", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .create();
+
+    let mut client = mock_client(&server);
+    assert!(client.headers().get("Accept-Language").is_none());
+    let _ = client.current_user_wallet().unwrap();
+    without_locale.assert();
+
+    let with_locale = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("accept-language", "es-ES")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .create();
+
+    client.set_options(
+        OptionsBuilder::default()
+            .locale("es-ES".to_owned())
+            .build()
+            .unwrap(),
+    );
+    assert_eq!(client.headers()["Accept-Language"], "es-ES");
+    let _ = client.current_user_wallet().unwrap();
+    with_locale.assert();
+}
+
+/// Tests the user agents built for each platform.
+#[test]
+fn it_options_build_user_agent() {
+    assert_eq!(
+        Options::build_user_agent(Platform::IPhone, "11.1"),
+        "Revolut/com.revolut.revolut (iPhone; iOS 11.1)"
+    );
+    assert_eq!(
+        Options::default().user_agent(),
+        &Options::build_user_agent(Platform::IPhone, "11.1")
+    );
+
+    let options = OptionsBuilder::default()
+        .user_agent(Options::build_user_agent(Platform::IPhone, "12.4"))
+        .build()
+        .unwrap();
+    assert_eq!(
+        Client::with_options(options).headers()[USER_AGENT],
+        "Revolut/com.revolut.revolut (iPhone; iOS 12.4)"
+    );
+}
+
+/// Tests the generation of the iPhone options.
+#[test]
+fn it_options_iphone_with() {
+    let options = Options::iphone_with("5.30", "12.1", "iPhone10,6");
+    assert_eq!(options.client_version(), "5.30");
+    assert_eq!(options.device_model(), "iPhone10,6");
+    assert_eq!(
+        options.user_agent(),
+        "Revolut/com.revolut.revolut (iPhone; iOS 12.1)"
+    );
+    assert_eq!(options.api_version(), Options::iphone().api_version());
+
+    let default = Options::iphone();
+    let options = Options::iphone_with(
+        default.client_version().as_str(),
+        "11.1",
+        default.device_model().as_str(),
+    );
+    assert_eq!(options.user_agent(), default.user_agent());
+}
+
+/// Transport returning scripted responses, in order.
+#[derive(Debug, Default)]
+struct ScriptedTransport {
+    /// Status codes and JSON bodies of the responses to return.
+    responses: Mutex<VecDeque<(u16, String)>>,
+    /// Methods and paths of the received requests.
+    requests: Mutex<Vec<String>>,
+}
+
+impl ScriptedTransport {
+    /// Creates a transport that will return the given responses.
+    fn new(responses: Vec<(u16, serde_json::Value)>) -> Self {
+        Self {
+            responses: Mutex::new(
+                responses
+                    .into_iter()
+                    .map(|(status, body)| (status, body.to_string()))
+                    .collect(),
+            ),
+            requests: Mutex::default(),
+        }
+    }
+}
+
+impl Transport for ScriptedTransport {
+    fn execute(&self, request: Request) -> Result<Response, anyhow::Error> {
+        self.requests.lock().unwrap().push(format!(
+            "{} {}",
+            request.method(),
+            request.url().path()
+        ));
+        let (status, body) = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected request");
+
+        Ok(http::Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .body(body)
+            .unwrap()
+            .into())
+    }
+}
+
+/// Tests the whole sign in process using a scripted transport.
+#[test]
+fn it_transport_sign_in() {
+    let transport = Arc::new(ScriptedTransport::new(vec![
+        (204, json!(null)),
+        (
+            200,
+            json!({
+                "user": user_json("+1555555555", "john.doe@example.com"),
+                "wallet": wallet_json(),
+                "accessToken": MOCK_ACCESS_TOKEN,
+            }),
+        ),
+        (400, json!({"message": "Insufficient funds", "code": 3001})),
+    ]));
+    let mut client = Client::default();
+    client.set_transport(Some(transport.clone()));
+
+    client.sign_in("+1555555555", "9999").unwrap();
+    let (user, wallet) = client
+        .confirm_sign_in("+1555555555", "111-111")
+        .unwrap()
+        .into_logged_in()
+        .unwrap();
+
+    assert_eq!(user.id(), MOCK_USER_ID.parse().unwrap());
+    assert_eq!(wallet.total_balance(), Amount::from_repr(170_25));
+    assert!(client.is_authenticated());
+    assert_eq!(client.access_token().unwrap(), MOCK_ACCESS_TOKEN);
+
+    let error = client
+        .withdraw(Uuid::nil(), Uuid::nil(), Amount::from_repr(1_000_00))
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.downcast_ref::<ApiError>().unwrap(),
+        &ApiError::BadRequest {
+            message: "Insufficient funds".to_owned(),
+            code: Some(3001),
+        }
+    );
+
+    assert_eq!(
+        *transport.requests.lock().unwrap(),
+        vec!["POST /signin", "POST /signin/confirm", "POST /withdrawal"]
+    );
+}
+
+/// Transport that always fails, without sending the requests.
+#[derive(Debug)]
+struct FailingTransport;
+
+impl Transport for FailingTransport {
+    fn execute(&self, _request: Request) -> Result<Response, anyhow::Error> {
+        Err(anyhow::anyhow!("network is down"))
+    }
+}
+
+/// Tests that the errors of the transport are reported as request failures.
+#[test]
+fn it_transport_failure() {
+    let client = Client::with_transport(FailingTransport);
+    let error = client.sign_in("+1555555555", "9999").unwrap_err();
+
+    assert_eq!(error.as_api_error(), Some(&ApiError::RequestFailure));
+    assert_eq!(error.root_cause().to_string(), "network is down");
+}
 
 /// Tests the user sign in.
 #[test]
@@ -24,6 +512,246 @@ fn it_sign_in() {
     );
 }
 
+/// Tests that the request fails when the server takes longer than the timeout to respond.
+#[test]
+fn it_sign_in_timeout() {
+    // The listener accepts connections but never responds to them.
+    let listener = TcpListener::bind("127.0.0.1:0").expect("could not bind the listener");
+    let address = listener.local_addr().unwrap();
+
+    let options = OptionsBuilder::default()
+        .timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+    let mut client = Client::with_options(options);
+    client
+        .set_base_url(Url::parse(&format!("http://{}/", address)).unwrap())
+        .unwrap();
+
+    let response = client.sign_in("+1555555555", "9999");
+
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::RequestFailure
+    );
+}
+
+/// Tests that the connection timeout only applies to establishing the connection.
+#[test]
+fn it_connect_timeout() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/signin")
+        .with_status(200)
+        .with_body_from_request(|_| {
+            thread::sleep(Duration::from_millis(300));
+            Vec::new()
+        })
+        .create();
+
+    let client = Client::builder()
+        .base_url(Url::parse(&server.url()).unwrap())
+        .connect_timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    client.sign_in("+1555555555", "9999").unwrap();
+    mock.assert();
+
+    // The backlog of the listener is filled, so that new connections are never established.
+    let listener = TcpListener::bind("127.0.0.1:0").expect("could not bind the listener");
+    let address = listener.local_addr().unwrap();
+    let mut pending = Vec::new();
+    while let Ok(stream) = TcpStream::connect_timeout(&address, Duration::from_millis(100)) {
+        pending.push(stream);
+    }
+
+    let client = Client::builder()
+        .base_url(Url::parse(&format!("http://{}/", address)).unwrap())
+        .connect_timeout(Duration::from_millis(200))
+        .build()
+        .unwrap();
+
+    let start = Instant::now();
+    let response = client.sign_in("+1555555555", "9999");
+
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::RequestFailure
+    );
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(200) && elapsed < Duration::from_secs(10));
+}
+
+/// Tests that the sign in fails without performing any request if the phone is not valid.
+#[test]
+fn it_sign_in_invalid_phone() {
+    let mut server = Server::new();
+    let mock = server.mock("POST", "/signin").expect(0).create();
+
+    let mut client = Client::default();
+    client
+        .set_base_url(Url::parse(&server.url()).unwrap())
+        .unwrap();
+
+    let response = client.sign_in("555-555-555", "9999");
+
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::InvalidPhone
+    );
+    mock.assert();
+}
+
+/// Tests that the sign-in confirmation code is sent again.
+#[test]
+fn it_resend_sign_in_code() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/signin/resend")
+        .match_body(Matcher::Json(json!({ "phone": "+1555555555" })))
+        .with_status(204)
+        .expect(1)
+        .create();
+    let rate_limited_mock = server
+        .mock("POST", "/signin/resend")
+        .match_body(Matcher::Json(json!({ "phone": "+1555555556" })))
+        .with_status(429)
+        .with_header("retry-after", "60")
+        .create();
+
+    let mut client = Client::default();
+    client
+        .set_base_url(Url::parse(&server.url()).unwrap())
+        .unwrap();
+
+    client.resend_sign_in_code("+1555555555").unwrap();
+    mock.assert();
+
+    let response = client.resend_sign_in_code("+1555555556");
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::RateLimited {
+            retry_after: Some(Duration::from_secs(60))
+        }
+    );
+    rate_limited_mock.assert();
+
+    let response = client.resend_sign_in_code("555-555-555");
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::InvalidPhone
+    );
+}
+
+/// Tests the extraction of the API error from the errors returned by the client.
+#[test]
+fn it_api_error_ext() {
+    let mut server = Server::new();
+    let mock = server.mock("POST", "/signin").with_status(401).create();
+
+    let client = mock_client(&server);
+
+    let error = client.sign_in("555-555-555", "9999").unwrap_err();
+    assert_eq!(error.as_api_error(), Some(&ApiError::InvalidPhone));
+
+    let error = client.sign_in("+1555555555", "9999").unwrap_err();
+    assert_eq!(error.as_api_error(), Some(&ApiError::Unauthorized));
+    mock.assert();
+
+    let error = anyhow::anyhow!("some other error");
+    assert_eq!(error.as_api_error(), None);
+}
+
+/// Tests the logging of the request bodies, with the secrets redacted.
+#[test]
+fn it_debug_bodies_redaction() {
+    let mut server = Server::new();
+    let sign_in_mock = server
+        .mock("POST", "/signin")
+        .with_status(204)
+        .expect(2)
+        .create();
+    let confirm_mock = server
+        .mock("POST", "/signin/confirm")
+        .with_status(401)
+        .create();
+    let wallet_mock = server
+        .mock("GET", "/user/current/wallet")
+        .with_status(200)
+        .with_body(wallet_json().to_string())
+        .create();
+
+    let logged = Arc::new(Mutex::new(Vec::new()));
+    let mut client = mock_client(&server);
+    client.set_body_logger({
+        let logged = Arc::clone(&logged);
+        move |body| logged.lock().unwrap().push(body.to_owned())
+    });
+
+    client.sign_in("+1555555555", "9999").unwrap();
+    assert!(logged.lock().unwrap().is_empty());
+
+    client.set_debug_bodies(true);
+    client.sign_in("+1555555555", "9999").unwrap();
+    assert!(client.confirm_sign_in("+1555555555", "123-456").is_err());
+    let _ = client.current_user_wallet().unwrap();
+
+    let logged = logged.lock().unwrap();
+    assert_eq!(logged.len(), 2);
+    for body in logged.iter() {
+        assert!(body.contains("+1555555555"));
+        assert!(body.contains("<redacted>"));
+        assert!(!body.contains("9999"));
+        assert!(!body.contains("123-456"));
+    }
+    sign_in_mock.assert();
+    confirm_mock.assert();
+    wallet_mock.assert();
+}
+
+/// Tests that the sign-in confirmation returns the verification challenge if the API requires an
+/// additional verification of the user.
+#[test]
+fn it_confirm_sign_in_verification_required() {
+    let mut server = Server::new();
+    let confirm_mock = server
+        .mock("POST", "/signin/confirm")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "verification": {
+                    "id": "4c5d6e7f-8a9b-4c0d-9e1f-2a3b4c5d6e7f",
+                    "type": "SELFIE",
+                }
+            })
+            .to_string(),
+        )
+        .create();
+
+    let mut client = Client::default();
+    client
+        .set_base_url(Url::parse(&server.url()).unwrap())
+        .unwrap();
+
+    let result = client.confirm_sign_in("+1555555555", "111-111").unwrap();
+    confirm_mock.assert();
+    assert!(!result.is_logged_in());
+    assert!(!client.is_authenticated());
+
+    match result {
+        ConfirmResult::VerificationRequired(challenge) => {
+            assert_eq!(
+                challenge.id(),
+                "4c5d6e7f-8a9b-4c0d-9e1f-2a3b4c5d6e7f".parse().unwrap()
+            );
+            assert_eq!(challenge.kind(), &VerificationType::Selfie);
+        }
+        ConfirmResult::LoggedIn(..) => panic!("the user should not be logged in"),
+    }
+}
+
 /// Tests the user sign in confirmation.
 #[ignore]
 #[test]
@@ -35,7 +763,7 @@ fn it_confirm_sign_in() {
     let code = env::var("TEST_CONFIRM_CODE").expect("no TEST_CONFIRM_CODE provided");
 
     let response = client.confirm_sign_in(&phone, &code);
-    assert!(response.is_ok());
+    assert!(response.unwrap().is_logged_in());
 }
 
 /// Tests the user retrieval.
@@ -55,6 +783,285 @@ fn it_current_user() {
     assert!(response.is_ok());
 }
 
+/// Tests that the user is retrieved even if the server sends an unexpected phone and email.
+#[test]
+fn it_current_user_lenient_contact() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "user": user_json("(555) 555-555", "john.doe"),
+                "wallet": wallet_json()
+            })
+            .to_string(),
+        )
+        .create();
+
+    let (user, _wallet) = mock_client(&server).current_user().unwrap();
+
+    assert_eq!(user.phone().as_str(), "(555) 555-555");
+    assert!(!user.phone().is_valid());
+    assert_eq!(user.email().as_str(), "john.doe");
+    assert!(!user.email().is_valid());
+    mock.assert();
+}
+
+/// Tests the decompression of gzip encoded responses.
+#[test]
+fn it_gzip_response() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(wallet_json().to_string().as_bytes())
+        .unwrap();
+    let body = encoder.finish().unwrap();
+
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("accept-encoding", Matcher::Regex("gzip".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("content-encoding", "gzip")
+        .with_body(body)
+        .create();
+
+    let wallet = mock_client(&server).current_user_wallet().unwrap();
+
+    assert_eq!(wallet.reference(), "1234567890");
+    assert_eq!(wallet.pockets().len(), 4);
+    mock.assert();
+}
+
+/// Tests the handling of successful responses with an empty body.
+#[test]
+fn it_empty_response() {
+    let mut server = Server::new();
+    let wallet_mock = server
+        .mock("GET", "/user/current/wallet")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("")
+        .create();
+    let cards_mock = server
+        .mock("GET", "/user/current/cards")
+        .with_status(204)
+        .create();
+    let devices_mock = server
+        .mock("GET", "/user/current/devices")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(" ")
+        .create();
+
+    let client = mock_client(&server);
+
+    let error = client.current_user_wallet().unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ApiError>(),
+        Some(&ApiError::EmptyResponse)
+    );
+    assert!(client.current_user_cards().unwrap().is_empty());
+    assert!(client.current_user_devices().unwrap().is_empty());
+
+    wallet_mock.assert();
+    cards_mock.assert();
+    devices_mock.assert();
+}
+
+/// Tests the parsing of captured API responses without a client.
+#[test]
+fn it_from_json_fixtures() {
+    let (user, wallet) =
+        User::from_current_user_json(include_str!("fixtures/current_user.json")).unwrap();
+    assert_eq!(user.id().to_string(), MOCK_USER_ID);
+    assert_eq!(user.first_name(), "John");
+    assert_eq!(wallet.pockets().len(), 2);
+
+    let user_json = serde_json::to_string(
+        &serde_json::from_str::<serde_json::Value>(include_str!("fixtures/current_user.json"))
+            .unwrap()["user"],
+    )
+    .unwrap();
+    assert_eq!(User::from_json(&user_json).unwrap(), user);
+    assert_eq!(
+        Wallet::from_json(include_str!("fixtures/wallet.json")).unwrap(),
+        wallet
+    );
+
+    let card = Card::from_json(include_str!("fixtures/card.json")).unwrap();
+    assert_eq!(card.last_four(), "1234");
+    assert_eq!(card.owner_id(), user.id());
+
+    let error = Card::from_json("{}").unwrap_err();
+    assert_eq!(error.as_api_error(), Some(&ApiError::ParseResponse));
+}
+
+/// Tests that the dates are parsed both from timestamps in milliseconds and RFC 3339 strings.
+#[test]
+fn it_timestamp_formats() {
+    let date = Utc.timestamp_millis_opt(1_546_300_800_000).unwrap();
+
+    let mut card = card_json("1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d");
+    let millis_card = Card::from_json(&card.to_string()).unwrap();
+    card["createdDate"] = json!("2019-01-01T00:00:00Z");
+    card["updatedDate"] = json!("2019-01-01T01:00:00+01:00");
+    card["lastUsedDate"] = json!("2019-01-01T00:00:00.000Z");
+    let rfc3339_card = Card::from_json(&card.to_string()).unwrap();
+
+    assert_eq!(millis_card.created_date(), date);
+    assert_eq!(rfc3339_card.created_date(), date);
+    assert_eq!(rfc3339_card.updated_date(), date);
+    assert_eq!(rfc3339_card.last_used_date(), date);
+    assert_eq!(rfc3339_card, millis_card);
+
+    let mut wallet = wallet_json();
+    let millis_wallet = Wallet::from_json(&wallet.to_string()).unwrap();
+    wallet["topupResetDate"] = json!("2019-01-01T00:00:00Z");
+    let rfc3339_wallet = Wallet::from_json(&wallet.to_string()).unwrap();
+    assert_eq!(millis_wallet.topup_reset_date(), date);
+    assert_eq!(rfc3339_wallet, millis_wallet);
+
+    wallet["topupResetDate"] = json!("yesterday");
+    assert_eq!(
+        Wallet::from_json(&wallet.to_string())
+            .unwrap_err()
+            .downcast_ref::<ApiError>(),
+        Some(&ApiError::ParseResponse)
+    );
+}
+
+/// Tests the deserialization of the auto-topup configuration of the cards.
+#[test]
+fn it_card_auto_topup() {
+    let mut card = card_json("1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d");
+    let auto_topup = Card::from_json(&card.to_string())
+        .unwrap()
+        .auto_topup()
+        .clone();
+    assert_eq!(auto_topup.status(), &AutoTopupStatus::Disabled);
+    assert_eq!(auto_topup.reason(), &None);
+    assert!(!auto_topup.is_enabled());
+
+    card["autoTopup"] = json!("ENABLED");
+    let parsed = Card::from_json(&card.to_string()).unwrap();
+    assert_eq!(parsed.auto_topup().status(), &AutoTopupStatus::Enabled);
+    assert!(parsed.auto_topup().is_enabled());
+
+    card["autoTopup"] = json!("suspended");
+    card["autoTopupReason"] = json!("INSUFFICIENT_FUNDS");
+    let parsed = Card::from_json(&card.to_string()).unwrap();
+    assert_eq!(parsed.auto_topup().status(), &AutoTopupStatus::Suspended);
+    assert_eq!(
+        parsed.auto_topup().reason().as_ref().map(String::as_str),
+        Some("INSUFFICIENT_FUNDS")
+    );
+
+    card["autoTopup"] = json!("PAUSED");
+    let _ = card.as_object_mut().unwrap().remove("autoTopupReason");
+    let parsed = Card::from_json(&card.to_string()).unwrap();
+    assert_eq!(
+        parsed.auto_topup().status(),
+        &AutoTopupStatus::Unknown("PAUSED".to_owned())
+    );
+    assert_eq!(parsed.auto_topup().reason(), &None);
+}
+
+/// Tests the equality of card issuers with equal fees.
+#[test]
+fn it_issuer_fee_equality() {
+    let mut card = card_json("1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d");
+    card["issuer"]["fee"] = json!(1.5);
+    let first = Card::from_json(&card.to_string()).unwrap();
+    card["issuer"]["fee"] = json!("1.50");
+    let second = Card::from_json(&card.to_string()).unwrap();
+    card["issuer"]["fee"] = json!(1.501);
+    let third = Card::from_json(&card.to_string()).unwrap();
+
+    assert_eq!(first.issuer().fee(), Amount::from_repr(1_50));
+    assert_eq!(first.issuer(), second.issuer());
+    assert_eq!(first.issuer(), third.issuer());
+    assert_eq!(first, second);
+
+    card["issuer"]["fee"] = json!(2);
+    let fourth = Card::from_json(&card.to_string()).unwrap();
+    assert_eq!(fourth.issuer().fee(), Amount::from_repr(2_00));
+    assert_ne!(first.issuer(), fourth.issuer());
+
+    card["issuer"]["fee"] = json!(-1.0);
+    assert!(Card::from_json(&card.to_string()).is_err());
+}
+
+/// Tests the display of users and cards.
+#[test]
+fn it_user_card_display() {
+    let user = User::from_json(&user_json("+15555555555", "john@example.com").to_string()).unwrap();
+    assert_eq!(user.to_string(), "John Doe <john@example.com>");
+
+    let mut card = card_json("1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d");
+    assert_eq!(
+        Card::from_json(&card.to_string()).unwrap().to_string(),
+        "VISA ****1234 (exp 12/30)"
+    );
+
+    card["brand"] = json!("MASTERCARD");
+    card["lastFour"] = json!("0042");
+    card["expiryDate"] = json!({ "year": 2009, "month": 3 });
+    assert_eq!(
+        Card::from_json(&card.to_string()).unwrap().to_string(),
+        "MASTERCARD ****0042 (exp 03/09)"
+    );
+}
+
+/// Tests the access to the IDs of the API types.
+#[test]
+fn it_api_type_ids() {
+    let (user, wallet) =
+        User::from_current_user_json(include_str!("fixtures/current_user.json")).unwrap();
+    assert_eq!(user.id(), MOCK_USER_ID.parse::<Uuid>().unwrap());
+    assert_eq!(
+        wallet.id(),
+        "9f8e7d6c-5b4a-4392-8170-6f5e4d3c2b1a"
+            .parse::<Uuid>()
+            .unwrap()
+    );
+    assert_eq!(
+        wallet.pockets()[0].id(),
+        "1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d"
+            .parse::<Uuid>()
+            .unwrap()
+    );
+
+    let card = Card::from_json(include_str!("fixtures/card.json")).unwrap();
+    assert_eq!(
+        card.id(),
+        "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d"
+            .parse::<Uuid>()
+            .unwrap()
+    );
+    assert_eq!(card.owner_id(), user.id());
+
+    let transaction: Transaction = serde_json::from_value(transaction_json(
+        "d7a8fbb3-07d4-4e8f-9a2e-3c5b6d7e8f90",
+        1_546_300_800_000,
+    ))
+    .unwrap();
+    assert_eq!(
+        transaction.id(),
+        "d7a8fbb3-07d4-4e8f-9a2e-3c5b6d7e8f90"
+            .parse::<Uuid>()
+            .unwrap()
+    );
+    assert_eq!(
+        transaction.leg_id(),
+        "d4e5f6a7-b8c9-4d0e-9f1a-2b3c4d5e6f7a"
+            .parse::<Uuid>()
+            .unwrap()
+    );
+}
+
 /// Tests the user wallet retrieval.
 #[test]
 fn it_current_user_wallet() {
@@ -123,3 +1130,2304 @@ fn it_change_current_user_address() {
     let (final_user, _wallet) = client.current_user().unwrap();
     assert_eq!(final_user.address(), previous_address);
 }
+
+/// Tests the withdrawal to a linked bank account.
+#[test]
+fn it_withdraw() {
+    let mut server = Server::new();
+    let pocket_id: Uuid = "f4a9a8b4-5e2a-4f5b-b4a5-0a0d8a7e3c11".parse().unwrap();
+    let bank_account_id: Uuid = "0b4c6f6e-9a6f-4e0b-8d2b-6f2f3c4d5e6f".parse().unwrap();
+
+    let mock = server
+        .mock("POST", "/withdrawal")
+        .match_body(Matcher::Json(json!({
+            "pocketId": pocket_id,
+            "bankAccountId": bank_account_id,
+            "amount": 25_50,
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "d7a8fbb3-07d4-4e8f-9a2e-3c5b6d7e8f90",
+                "legId": "e1b2c3d4-5f6a-4b7c-8d9e-0f1a2b3c4d5e",
+                "type": "TRANSFER",
+                "state": "PENDING",
+                "startedDate": 1_546_300_800_000_i64,
+                "updatedDate": 1_546_300_800_000_i64,
+                "currency": "EUR",
+                "amount": -25_50,
+                "fee": 0,
+                "description": "To My Bank",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let client = mock_client(&server);
+    let transaction = client
+        .withdraw(pocket_id, bank_account_id, Amount::from_repr(25_50))
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(
+        transaction.amount(),
+        SignedAmount::debit(Amount::from_repr(25_50))
+    );
+    assert_eq!(transaction.state(), "PENDING");
+}
+
+/// Tests the withdrawal to a linked bank account without enough funds.
+#[test]
+fn it_withdraw_insufficient_funds() {
+    let mut server = Server::new();
+
+    let _mock = server
+        .mock("POST", "/withdrawal")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message":"Insufficient funds","code":3001}"#)
+        .create();
+
+    let client = mock_client(&server);
+    let response = client.withdraw(Uuid::nil(), Uuid::nil(), Amount::from_repr(1_000_00));
+
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::BadRequest {
+            message: "Insufficient funds".to_owned(),
+            code: Some(3001),
+        }
+    );
+}
+
+/// Tests the conversion of the `400` error responses into `ApiError::BadRequest`.
+#[test]
+fn it_bad_request_response() {
+    let mut server = Server::new();
+    let confirm_mock = server
+        .mock("POST", "/signin/confirm")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message":"Invalid code","code":9002}"#)
+        .create();
+    let address_mock = server
+        .mock("PATCH", "/user/current")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message":"Invalid postcode"}"#)
+        .create();
+
+    let mut client = mock_client(&server);
+    let response = client.confirm_sign_in("+1555555555", "111-111");
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::BadRequest {
+            message: "Invalid code".to_owned(),
+            code: Some(9002),
+        }
+    );
+    confirm_mock.assert();
+
+    let address = Address::new("New City", "FR", "39325", "NewRegion", "Street 1, 6", None);
+    let response = client.change_current_user_address(&address);
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::BadRequest {
+            message: "Invalid postcode".to_owned(),
+            code: None,
+        }
+    );
+    address_mock.assert();
+}
+
+/// Tests the deserialization of the card brands.
+#[test]
+fn it_card_brand_deserialize() {
+    let brands = [
+        ("VISA", CardBrand::Visa),
+        ("MASTERCARD", CardBrand::Mastercard),
+        ("MAESTRO", CardBrand::Maestro),
+        ("AMEX", CardBrand::AmericanExpress),
+        ("visa", CardBrand::Visa),
+    ];
+    for (wire, brand) in brands.iter() {
+        let deserialized: CardBrand = serde_json::from_value(json!(wire)).unwrap();
+        assert_eq!(&deserialized, brand);
+    }
+
+    let unknown: CardBrand = serde_json::from_value(json!("NEWBRAND")).unwrap();
+    assert_eq!(unknown, CardBrand::Unknown("NEWBRAND".to_owned()));
+    assert_eq!(format!("{}", unknown), "NEWBRAND");
+
+    assert_eq!(format!("{}", CardBrand::Mastercard), "MASTERCARD");
+    assert_eq!(format!("{}", CardBrand::AmericanExpress), "AMEX");
+    assert_eq!(CardBrand::DinersClub.as_str(), "DINERS");
+}
+
+/// Tests that the user information is deserialized without the fields of unknown meaning.
+#[test]
+fn it_user_deserialize_missing_fields() {
+    let user: User =
+        serde_json::from_value(user_json("+1555555555", "john.doe@example.com")).unwrap();
+    assert_eq!(user.kyc().as_ref().map(String::as_str), Some("PASSED"));
+    assert_eq!(user.sof().as_ref().unwrap().state(), "PASSED");
+
+    let mut json = user_json("+1555555555", "john.doe@example.com");
+    let object = json.as_object_mut().unwrap();
+    let _ = object.remove("sof");
+    let _ = object.remove("kyc");
+    let _ = object.remove("riskAssessed");
+    let _ = object.insert("underReview".to_owned(), serde_json::Value::Null);
+
+    let user: User = serde_json::from_value(json).unwrap();
+    assert_eq!(user.kyc(), &None);
+    assert_eq!(user.sof(), &None);
+    assert!(!user.under_review());
+    assert!(!user.risk_assessed());
+    assert_eq!(user.first_name(), "John");
+}
+
+/// Tests the deserialization of the user states.
+#[test]
+fn it_user_state_deserialize() {
+    let user: User =
+        serde_json::from_value(user_json("+1555555555", "john.doe@example.com")).unwrap();
+    assert_eq!(user.state(), &UserState::Active);
+    assert!(user.is_usable());
+
+    for (state_str, state) in &[
+        ("ACTIVE", UserState::Active),
+        ("PENDING", UserState::Pending),
+        ("LOCKED", UserState::Locked),
+        ("closed", UserState::Closed),
+        ("FROZEN", UserState::Unknown("FROZEN".to_owned())),
+    ] {
+        let mut json = user_json("+1555555555", "john.doe@example.com");
+        let _ = json
+            .as_object_mut()
+            .unwrap()
+            .insert("state".to_owned(), json!(state_str));
+
+        let user: User = serde_json::from_value(json).unwrap();
+        assert_eq!(user.state(), state);
+        assert_eq!(user.is_usable(), *state == UserState::Active);
+    }
+    assert_eq!(UserState::Unknown("FROZEN".to_owned()).as_str(), "FROZEN");
+
+    let mut json = user_json("+1555555555", "john.doe@example.com");
+    let _ = json
+        .as_object_mut()
+        .unwrap()
+        .insert("underReview".to_owned(), json!(true));
+    let user: User = serde_json::from_value(json).unwrap();
+    assert_eq!(user.state(), &UserState::Active);
+    assert!(!user.is_usable());
+}
+
+/// Tests the deserialization of the wallet and pocket states and types.
+#[test]
+fn it_pocket_state_deserialize() {
+    let wallet: Wallet = serde_json::from_value(wallet_json()).unwrap();
+    assert_eq!(wallet.state(), &WalletState::Active);
+
+    let pockets = wallet.pockets();
+    assert_eq!(pockets[0].pocket_type(), &PocketType::Current);
+    assert_eq!(pockets[1].pocket_type(), &PocketType::Savings);
+    assert_eq!(pockets[0].state(), &PocketState::Active);
+    assert_eq!(pockets[3].state(), &PocketState::Closed);
+
+    let state: PocketState = serde_json::from_value(json!("inactive")).unwrap();
+    assert_eq!(state, PocketState::Inactive);
+    assert_eq!(state.as_str(), "INACTIVE");
+
+    let pocket_type: PocketType = serde_json::from_value(json!("CRYPTO")).unwrap();
+    assert_eq!(pocket_type, PocketType::Unknown("CRYPTO".to_owned()));
+    assert_eq!(pocket_type.as_str(), "CRYPTO");
+    assert_eq!(format!("{}", pocket_type), "CRYPTO");
+
+    let state: WalletState = serde_json::from_value(json!("LOCKED")).unwrap();
+    assert_eq!(state, WalletState::Unknown("LOCKED".to_owned()));
+    assert_eq!(format!("{}", WalletState::Inactive), "INACTIVE");
+}
+
+/// Tests the conversion of card types to and from strings.
+#[test]
+fn it_card_type_string() {
+    assert_eq!(format!("{}", CardType::Credit), "CREDIT");
+    assert_eq!(format!("{}", CardType::Debit), "DEBIT");
+
+    assert_eq!("CREDIT".parse::<CardType>().unwrap(), CardType::Credit);
+    assert_eq!("debit".parse::<CardType>().unwrap(), CardType::Debit);
+
+    let error = "PREPAID".parse::<CardType>().err().unwrap();
+    assert!(error.downcast_ref::<CardTypeParseError>().is_some());
+
+    assert_eq!(
+        serde_json::to_value(CardType::Debit).unwrap(),
+        json!("DEBIT")
+    );
+    let card_type: CardType = serde_json::from_value(json!("CREDIT")).unwrap();
+    assert_eq!(card_type, CardType::Credit);
+}
+
+/// Tests the card expiry date.
+#[test]
+fn it_card_expiry_date() {
+    let expiry: ExpiryDate = serde_json::from_value(json!({"year": 2020, "month": 2})).unwrap();
+    assert_eq!(expiry.year(), 2020);
+    assert_eq!(expiry.month(), 2);
+    assert_eq!(expiry.last_day(), NaiveDate::from_ymd(2020, 2, 29));
+    assert!(!expiry.is_expired_at(Utc.ymd(2020, 2, 29).and_hms(23, 59, 59)));
+    assert!(expiry.is_expired_at(Utc.ymd(2020, 3, 1).and_hms(0, 0, 0)));
+
+    let expiry: ExpiryDate = serde_json::from_value(json!({"year": 2021, "month": 12})).unwrap();
+    assert_eq!(expiry.last_day(), NaiveDate::from_ymd(2021, 12, 31));
+    assert_eq!(ExpiryDate::new(2021, 12), Some(expiry));
+
+    for month in &[0, 13] {
+        assert!(ExpiryDate::new(2021, *month).is_none());
+        assert!(
+            serde_json::from_value::<ExpiryDate>(json!({"year": 2021, "month": month})).is_err()
+        );
+    }
+    assert!(ExpiryDate::new(i32::max_value(), 12).is_none());
+}
+
+/// Tests the wallet top-up from a card.
+#[test]
+fn it_top_up() {
+    let mut server = Server::new();
+    let card_id: Uuid = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d".parse().unwrap();
+
+    let mock = server
+        .mock("POST", "/user/current/wallet/topup")
+        .match_body(Matcher::Json(json!({
+            "cardId": card_id,
+            "amount": 50_00,
+            "currency": "EUR",
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "c3d4e5f6-a7b8-4c9d-8e0f-1a2b3c4d5e6f",
+                "legId": "d4e5f6a7-b8c9-4d0e-9f1a-2b3c4d5e6f7a",
+                "type": "TOPUP",
+                "state": "COMPLETED",
+                "startedDate": 1_546_300_800_000_i64,
+                "updatedDate": 1_546_300_800_000_i64,
+                "currency": "EUR",
+                "amount": 50_00,
+                "fee": 0,
+                "description": "Top-Up by *1234",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let client = mock_client(&server);
+    let transaction = client
+        .top_up(card_id, Amount::from_repr(50_00), "EUR")
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(
+        transaction.amount(),
+        SignedAmount::credit(Amount::from_repr(50_00))
+    );
+    assert_eq!(transaction.transaction_type(), "TOPUP");
+}
+
+/// Tests the user sign out.
+#[test]
+fn it_sign_out() {
+    let mut server = Server::new();
+
+    let mock = server
+        .mock("POST", "/signout")
+        .match_header("authorization", Matcher::Regex("^Basic ".to_owned()))
+        .with_status(204)
+        .create();
+
+    let mut client = mock_client(&server);
+    client.sign_out().unwrap();
+
+    mock.assert();
+    assert!(client.user_id().is_none());
+    assert!(client.access_token().is_none());
+
+    let response = client.sign_out();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotLoggedIn
+    );
+}
+
+/// Tests the renewal of the access token.
+#[test]
+fn it_refresh_token() {
+    let mut server = Server::new();
+    let new_token = "9a4d5a6c-3b2e-4f1d-8c7b-6a5f4e3d2c1b";
+
+    let mock = server
+        .mock("POST", "/signin/refresh")
+        .match_header("authorization", Matcher::Regex("^Basic ".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "accessToken": new_token }).to_string())
+        .create();
+
+    let mut client = mock_client(&server);
+    client.refresh_token().unwrap();
+
+    mock.assert();
+    assert_eq!(client.user_id(), Some(MOCK_USER_ID.parse().unwrap()));
+    assert_eq!(client.access_token().unwrap(), new_token);
+
+    client.unset_auth();
+    let response = client.refresh_token();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotLoggedIn
+    );
+}
+
+/// Tests that an expired access token can't be renewed.
+#[test]
+fn it_refresh_token_expired() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/signin/refresh")
+        .with_status(401)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"message": "The access token has expired"}).to_string())
+        .create();
+
+    let mut client = mock_client(&server);
+    let response = client.refresh_token();
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::TokenExpired
+    );
+    assert_eq!(client.access_token().unwrap(), MOCK_ACCESS_TOKEN);
+}
+
+/// Tests that the user sign out removes the authentication information even on failure.
+#[test]
+fn it_sign_out_failure() {
+    let mut server = Server::new();
+
+    let _mock = server.mock("POST", "/signout").with_status(401).create();
+
+    let mut client = mock_client(&server);
+    let response = client.sign_out();
+
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Unauthorized
+    );
+    assert!(client.user_id().is_none());
+    assert!(client.access_token().is_none());
+}
+
+/// Tests that the idempotency key is sent, and that it's stable across retried calls.
+#[test]
+fn it_idempotency_key() {
+    let idempotency_key = Uuid::new_v4();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d".parse().unwrap();
+
+    let mut server = Server::new();
+    let keyed_mock = server
+        .mock("POST", "/user/current/wallet/topup")
+        .match_header("idempotency-key", idempotency_key.to_string().as_str())
+        .with_status(503)
+        .expect(2)
+        .create();
+    let random_mock = server
+        .mock("POST", "/user/current/wallet/topup")
+        .match_header(
+            "idempotency-key",
+            Matcher::Regex("^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-".to_owned()),
+        )
+        .with_status(503)
+        .expect(1)
+        .create();
+    let wallet_mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("idempotency-key", Matcher::Missing)
+        .with_status(200)
+        .with_body(wallet_json().to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let keyed_client = client.with_idempotency_key(idempotency_key);
+    for _ in 0..2 {
+        assert!(keyed_client
+            .top_up(card_id, Amount::from_repr(50_00), "EUR")
+            .is_err());
+    }
+    keyed_mock.assert();
+
+    assert!(client
+        .top_up(card_id, Amount::from_repr(50_00), "EUR")
+        .is_err());
+    random_mock.assert();
+
+    let _ = keyed_client.current_user_wallet().unwrap();
+    wallet_mock.assert();
+}
+
+/// Tests that the idempotency key is sent by the operations that change the user data.
+#[test]
+fn it_idempotency_key_user_data() {
+    let idempotency_key = Uuid::new_v4();
+    let card_id: Uuid = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d".parse().unwrap();
+    let pocket_id: Uuid = "2b3c4d5e-6f7a-4b8c-9d0e-1f2a3b4c5d6e".parse().unwrap();
+    let device_id = Uuid::new_v4();
+
+    let mut server = Server::new();
+    let mocks = vec![
+        ("PATCH", format!("/user/current/cards/{}", card_id)),
+        ("POST", format!("/user/current/cards/{}/verify", card_id)),
+        (
+            "PATCH",
+            format!("/user/current/wallet/pockets/{}", pocket_id),
+        ),
+        ("DELETE", format!("/user/current/devices/{}", device_id)),
+    ]
+    .into_iter()
+    .map(|(method, path)| {
+        server
+            .mock(method, path.as_str())
+            .match_header("idempotency-key", idempotency_key.to_string().as_str())
+            .with_status(503)
+            .create()
+    })
+    .collect::<Vec<_>>();
+
+    let client = mock_client(&server).with_idempotency_key(idempotency_key);
+    assert!(client.set_auto_topup(card_id, false, None, None).is_err());
+    assert!(client.verify_card(card_id, "123456").is_err());
+    assert!(client.close_pocket(pocket_id).is_err());
+    assert!(client.revoke_device(device_id).is_err());
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+/// Tests the total balance computation of a wallet.
+#[test]
+fn it_wallet_total_balance() {
+    let wallet: Wallet = serde_json::from_value(wallet_json()).unwrap();
+
+    assert_eq!(wallet.total_balance(), Amount::from_repr(170_25));
+    assert_eq!(wallet.total_balance_in("EUR"), Amount::from_repr(170_25));
+    assert_eq!(wallet.total_balance_in("GBP"), Amount::from_repr(30_50));
+    assert_eq!(wallet.total_balance_in("USD"), Amount::min_value());
+}
+
+/// Tests the lookup of the pockets of a wallet.
+#[test]
+fn it_wallet_pocket_lookup() {
+    let wallet: Wallet = serde_json::from_value(wallet_json()).unwrap();
+
+    let id: Uuid = "2b3c4d5e-6f7a-4b8c-9d0e-1f2a3b4c5d6e".parse().unwrap();
+    let pocket = wallet.pocket_by_id(id).unwrap();
+    assert_eq!(pocket.pocket_type(), &PocketType::Savings);
+    assert_eq!(pocket.balance(), Amount::from_repr(20_00));
+    assert!(wallet.pocket_by_id(Uuid::nil()).is_none());
+
+    let pocket = wallet.pocket_by_currency("EUR").unwrap();
+    assert_eq!(pocket.pocket_type(), &PocketType::Current);
+    assert_eq!(pocket.balance(), Amount::from_repr(150_25));
+    let pocket = wallet.pocket_by_currency("GBP").unwrap();
+    assert_eq!(pocket.balance(), Amount::from_repr(30_50));
+    assert!(wallet.pocket_by_currency("USD").is_none());
+    assert!(wallet.pocket_by_currency("CHF").is_none());
+
+    let pockets = wallet.clone().into_pockets();
+    assert_eq!(pockets.as_slice(), wallet.pockets());
+}
+
+/// Tests the closing and opening of a pocket.
+#[test]
+fn it_close_open_pocket() {
+    let mut server = Server::new();
+    let pocket_id = "3c4d5e6f-7a8b-4c9d-0e1f-2a3b4c5d6e7f";
+    let path = format!("/user/current/wallet/pockets/{}", pocket_id);
+    let mut pocket = wallet_json()["pockets"][2].clone();
+
+    let _ = pocket
+        .as_object_mut()
+        .unwrap()
+        .insert("state".to_owned(), json!("CLOSED"));
+    let _ = pocket
+        .as_object_mut()
+        .unwrap()
+        .insert("closed".to_owned(), json!(true));
+    let close_mock = server
+        .mock("PATCH", path.as_str())
+        .match_body(Matcher::Json(json!({ "state": "CLOSED" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(pocket.to_string())
+        .create();
+    let open_mock = server
+        .mock("PATCH", path.as_str())
+        .match_body(Matcher::Json(json!({ "state": "ACTIVE" })))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"message": "Pocket can't be opened", "code": 3020}).to_string())
+        .create();
+
+    let mut client = mock_client(&server);
+
+    let pocket = client.close_pocket(pocket_id.parse().unwrap()).unwrap();
+    assert_eq!(pocket.state(), &PocketState::Closed);
+    assert!(pocket.closed());
+    close_mock.assert();
+
+    let response = client.open_pocket(pocket_id.parse().unwrap());
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::BadRequest {
+            message: "Pocket can't be opened".to_owned(),
+            code: Some(3020),
+        }
+    );
+    open_mock.assert();
+
+    client.unset_auth();
+    let response = client.close_pocket(pocket_id.parse().unwrap());
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotLoggedIn
+    );
+}
+
+/// Tests that the balance of a pocket is returned together with its currency.
+#[test]
+fn it_pocket_money_balance() {
+    let wallet: Wallet = serde_json::from_value(wallet_json()).unwrap();
+    let pockets = wallet.pockets();
+
+    let eur = pockets[0].money_balance();
+    let gbp = pockets[2].money_balance();
+    assert_eq!(eur, Money::new(Amount::from_repr(150_25), "EUR"));
+    assert_eq!(gbp, Money::new(Amount::from_repr(30_50), "GBP"));
+
+    assert_eq!(
+        eur.checked_add(&pockets[1].money_balance()),
+        Some(Money::new(Amount::from_repr(170_25), "EUR"))
+    );
+    assert_eq!(eur.checked_add(&gbp), None);
+}
+
+/// Tests the exchange quote retrieval and the exchange itself.
+#[test]
+fn it_exchange() {
+    let mut server = Server::new();
+
+    let quote_mock = server
+        .mock("GET", "/quote")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("from".to_owned(), "EUR".to_owned()),
+            Matcher::UrlEncoded("to".to_owned(), "USD".to_owned()),
+            Matcher::UrlEncoded("amount".to_owned(), "1000".to_owned()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "from": "EUR",
+                "to": "USD",
+                "rate": 1.17,
+                "fromAmount": 10_00,
+                "toAmount": 11_70,
+                "fee": 0,
+            })
+            .to_string(),
+        )
+        .create();
+
+    let client = mock_client(&server);
+    let quote = client
+        .exchange_quote("EUR", "USD", Amount::from_repr(10_00))
+        .unwrap();
+
+    quote_mock.assert();
+    assert_eq!(quote.to_amount(), Amount::from_repr(11_70));
+    assert!((quote.rate() - 1.17).abs() < f64::EPSILON);
+
+    let exchange_mock = server
+        .mock("POST", "/exchange")
+        .match_body(Matcher::Json(json!({
+            "from": { "currency": "EUR", "amount": 10_00 },
+            "to": { "currency": "USD", "amount": 11_70 },
+            "rate": 1.17,
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create();
+
+    let transactions = client.exchange(&quote).unwrap();
+
+    exchange_mock.assert();
+    assert!(transactions.is_empty());
+}
+
+/// Tests that the body of unexpected responses is kept in the error.
+#[test]
+fn it_unexpected_response_body() {
+    let mut server = Server::new();
+
+    let _mock = server
+        .mock("GET", "/user/current/wallet")
+        .with_status(418)
+        .with_body("I'm a teapot")
+        .create();
+
+    let client = mock_client(&server);
+    let response = client.current_user_wallet();
+
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Other {
+            status_code: StatusCode::IM_A_TEAPOT,
+            body: Some("I'm a teapot".to_owned()),
+        }
+    );
+}
+
+/// Tests building an authenticated client in one expression.
+#[test]
+fn it_client_builder() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("user-agent", "Revolut/com.revolut.revolut (android)")
+        .match_header("authorization", Matcher::Regex("^Basic ".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .create();
+
+    let client = Client::builder()
+        .options(Options::android())
+        .base_url(Url::parse(&server.url()).unwrap())
+        .timeout(Duration::from_secs(5))
+        .user_id(MOCK_USER_ID)
+        .access_token(MOCK_ACCESS_TOKEN)
+        .build()
+        .unwrap();
+
+    assert_eq!(client.user_id(), Some(MOCK_USER_ID.parse().unwrap()));
+    assert_eq!(client.access_token().unwrap(), MOCK_ACCESS_TOKEN);
+
+    let wallet = client.current_user_wallet().unwrap();
+    assert_eq!(wallet.total_balance(), Amount::from_repr(170_25));
+    mock.assert();
+}
+
+/// Tests that the client builder requires both the user ID and the access token.
+#[test]
+fn it_client_builder_incomplete_auth() {
+    let client = Client::builder().user_id(MOCK_USER_ID).build();
+    assert_eq!(
+        client.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::IncompleteAuth
+    );
+
+    let client = Client::builder().access_token(MOCK_ACCESS_TOKEN).build();
+    assert_eq!(
+        client.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::IncompleteAuth
+    );
+
+    let client = Client::builder().build().unwrap();
+    assert!(client.user_id().is_none());
+}
+
+/// Tests whether the client is authenticated after setting and unsetting the authentication.
+#[test]
+fn it_client_is_authenticated() {
+    let mut client = Client::default();
+    assert!(!client.is_authenticated());
+
+    client.set_auth(MOCK_USER_ID, MOCK_ACCESS_TOKEN).unwrap();
+    assert!(client.is_authenticated());
+
+    client.unset_auth();
+    assert!(!client.is_authenticated());
+
+    let client = Client::builder()
+        .user_id(MOCK_USER_ID)
+        .access_token(MOCK_ACCESS_TOKEN)
+        .build()
+        .unwrap();
+    assert!(client.is_authenticated());
+}
+
+/// Tests the card confirmation.
+#[test]
+fn it_confirm_card() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+
+    let mock = server
+        .mock(
+            "POST",
+            format!("/user/current/cards/{}/confirm", card_id).as_str(),
+        )
+        .match_body(Matcher::Json(json!({ "amount": 1_23 })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(card_json(card_id).to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let card = client
+        .confirm_card(card_id.parse().unwrap(), Amount::from_repr(1_23))
+        .unwrap();
+
+    mock.assert();
+    assert!(card.confirmed());
+    assert_eq!(card.confirmation_attempts(), 1);
+}
+
+/// Tests the card confirmation when the confirmation attempts were exhausted.
+#[test]
+fn it_confirm_card_attempts_exhausted() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+
+    let mock = server
+        .mock(
+            "POST",
+            format!("/user/current/cards/{}/confirm", card_id).as_str(),
+        )
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "message": "Card confirmation attempts exceeded",
+                "code": 3014,
+            })
+            .to_string(),
+        )
+        .create();
+
+    let client = mock_client(&server);
+    let response = client.confirm_card(card_id.parse().unwrap(), Amount::from_repr(1_23));
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::BadRequest {
+            message: "Card confirmation attempts exceeded".to_owned(),
+            code: Some(3014),
+        }
+    );
+}
+
+/// Tests the card verification with a code.
+#[test]
+fn it_verify_card() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+
+    let mock = server
+        .mock(
+            "POST",
+            format!("/user/current/cards/{}/verify", card_id).as_str(),
+        )
+        .match_body(Matcher::Json(json!({ "code": "123456" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(card_json(card_id).to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let card = client
+        .verify_card(card_id.parse().unwrap(), "123456")
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(card.id(), card_id.parse().unwrap());
+    assert!(card.confirmed());
+}
+
+/// Tests the card verification when the confirmation attempts were exhausted.
+#[test]
+fn it_verify_card_attempts_exhausted() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+
+    let verify_mock = server
+        .mock(
+            "POST",
+            format!("/user/current/cards/{}/verify", card_id).as_str(),
+        )
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Card confirmation attempts exceeded" }).to_string())
+        .create();
+    let mut card = card_json(card_id);
+    card["confirmed"] = json!(false);
+    card["confirmationAttempts"] = json!(3);
+    let card_mock = server
+        .mock("GET", format!("/user/current/cards/{}", card_id).as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(card.to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let card_id = card_id.parse().unwrap();
+    let error = client.verify_card(card_id, "000000").unwrap_err();
+    verify_mock.assert();
+    assert_eq!(
+        error.as_api_error(),
+        Some(&ApiError::BadRequest {
+            message: "Card confirmation attempts exceeded".to_owned(),
+            code: None,
+        })
+    );
+
+    let card = client.current_user_card(card_id).unwrap();
+    card_mock.assert();
+    assert!(!card.confirmed());
+    assert_eq!(card.confirmation_attempts(), 3);
+}
+
+/// Tests the change of the auto-topup configuration of a card.
+#[test]
+fn it_set_auto_topup() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+    let path = format!("/user/current/cards/{}", card_id);
+
+    let mut card = card_json(card_id);
+    card["autoTopup"] = json!("ENABLED");
+    let enable_mock = server
+        .mock("PATCH", path.as_str())
+        .match_body(Matcher::Json(json!({
+            "autoTopup": "ENABLED",
+            "autoTopupThreshold": 10_00,
+            "autoTopupAmount": 50_00,
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(card.to_string())
+        .create();
+    let disable_mock = server
+        .mock("PATCH", path.as_str())
+        .match_body(Matcher::Json(json!({ "autoTopup": "DISABLED" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(card_json(card_id).to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let card = client
+        .set_auto_topup(
+            card_id.parse().unwrap(),
+            true,
+            Some(Amount::from_repr(10_00)),
+            Some(Amount::from_repr(50_00)),
+        )
+        .unwrap();
+    assert!(card.auto_topup().is_enabled());
+
+    let card = client
+        .set_auto_topup(card_id.parse().unwrap(), false, None, None)
+        .unwrap();
+    assert!(!card.auto_topup().is_enabled());
+
+    enable_mock.assert();
+    disable_mock.assert();
+}
+
+/// Tests the validation of the auto-topup configuration of a card.
+#[test]
+fn it_set_auto_topup_validation() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+    let path = format!("/user/current/cards/{}", card_id);
+
+    let mock = server
+        .mock("PATCH", path.as_str())
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Auto-topup amount is too low" }).to_string())
+        .expect(1)
+        .create();
+
+    let client = mock_client(&server);
+    let error = client
+        .set_auto_topup(
+            card_id.parse().unwrap(),
+            true,
+            Some(Amount::from_repr(10_00)),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ApiError>(),
+        Some(&ApiError::InvalidAutoTopup)
+    );
+
+    let error = client
+        .set_auto_topup(
+            card_id.parse().unwrap(),
+            true,
+            Some(Amount::from_repr(10_00)),
+            Some(Amount::from_repr(0_01)),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ApiError>(),
+        Some(&ApiError::BadRequest {
+            message: "Auto-topup amount is too low".to_owned(),
+            code: None,
+        })
+    );
+
+    mock.assert();
+}
+
+/// Tests that the extra headers are merged with the headers generated from the options.
+#[test]
+fn it_client_headers() {
+    let mut client = Client::default();
+
+    let headers = client.headers();
+    assert_eq!(headers["X-Client-Version"], "5.29");
+    assert_eq!(headers["X-Device-Model"], "iPhone8,1");
+    assert_eq!(
+        headers[USER_AGENT],
+        "Revolut/com.revolut.revolut (iPhone; iOS 11.1)"
+    );
+
+    let mut extra_headers = HeaderMap::new();
+    let _ = extra_headers.insert("X-Verify-Password", HeaderValue::from_static("secret"));
+    let _ = extra_headers.insert(USER_AGENT, HeaderValue::from_static("custom-agent"));
+    client.set_extra_headers(extra_headers);
+
+    let headers = client.headers();
+    assert_eq!(headers["X-Verify-Password"], "secret");
+    assert_eq!(headers.get_all(USER_AGENT).iter().count(), 1);
+    assert_eq!(headers[USER_AGENT], "custom-agent");
+    assert_eq!(headers["X-Client-Version"], "5.29");
+}
+
+/// Tests that the extra headers are sent in the requests.
+#[test]
+fn it_extra_headers() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("X-Verify-Password", "secret")
+        .match_header("user-agent", "custom-agent")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .create();
+
+    let mut extra_headers = HeaderMap::new();
+    let _ = extra_headers.insert("X-Verify-Password", HeaderValue::from_static("secret"));
+    let _ = extra_headers.insert(USER_AGENT, HeaderValue::from_static("custom-agent"));
+
+    let mut client = mock_client(&server);
+    client.set_extra_headers(extra_headers);
+    let _ = client.current_user_wallet().unwrap();
+
+    mock.assert();
+}
+
+/// Tests that an expired access token is reported as such.
+#[test]
+fn it_token_expired() {
+    let mut server = Server::new();
+    let body = json!({"message": "The access token has expired"}).to_string();
+    let user_mock = server
+        .mock("GET", "/user/current")
+        .with_status(401)
+        .with_header("content-type", "application/json")
+        .with_body(&body)
+        .create();
+    let address_mock = server
+        .mock("PATCH", "/user/current")
+        .with_status(401)
+        .with_header("content-type", "application/json")
+        .with_body(&body)
+        .create();
+
+    let client = mock_client(&server);
+    let address: Address = serde_json::from_value(json!({
+        "city": "New City",
+        "country": "FR",
+        "postcode": "39325",
+        "region": "NewRegion",
+        "streetLine1": "Street 1, 6",
+        "streetLine2": "Apt. 5"
+    }))
+    .unwrap();
+
+    let response = client.current_user();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::TokenExpired
+    );
+    let response = client.change_current_user_address(&address);
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::TokenExpired
+    );
+
+    user_mock.assert();
+    address_mock.assert();
+}
+
+/// Tests that other unauthorized responses mentioning an expiry are not reported as an expired
+/// access token.
+#[test]
+fn it_unauthorized_not_token_expired() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/cards")
+        .with_status(401)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"message": "The card has expired"}).to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let response = client.current_user_cards();
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Unauthorized
+    );
+}
+
+/// Tests that a rate limited response surfaces the time to wait from the `Retry-After` header.
+#[test]
+fn it_rate_limited() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current")
+        .with_status(429)
+        .with_header("retry-after", "30")
+        .create();
+
+    let response = mock_client(&server).current_user();
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::RateLimited {
+            retry_after: Some(Duration::from_secs(30))
+        }
+    );
+}
+
+/// Tests that a rate limited response without a `Retry-After` header is still recognized.
+#[test]
+fn it_rate_limited_without_retry_after() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/wallet")
+        .with_status(429)
+        .create();
+
+    let response = mock_client(&server).current_user_wallet();
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::RateLimited { retry_after: None }
+    );
+}
+
+/// Tests that an unrecognized unauthorized response is reported as unauthorized.
+#[test]
+fn it_unauthorized_unknown_body() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/wallet")
+        .with_status(401)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"message": "Invalid credentials", "code": 9002}).to_string())
+        .create();
+
+    let response = mock_client(&server).current_user_wallet();
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Unauthorized
+    );
+}
+
+/// Tests that an unauthorized response without body is reported as unauthorized.
+#[test]
+fn it_unauthorized_no_body() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/cards")
+        .with_status(401)
+        .create();
+
+    let response = mock_client(&server).current_user_cards();
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Unauthorized
+    );
+}
+
+/// Tests that the requests are sent through the proxy.
+#[test]
+fn it_proxy() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("host", "api.example.com")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .create();
+
+    let mut client = Client::with_proxy(Proxy::http(&server.url()).unwrap());
+    client
+        .set_base_url(Url::parse("http://api.example.com/").unwrap())
+        .unwrap();
+    client
+        .set_auth(MOCK_USER_ID, MOCK_ACCESS_TOKEN)
+        .expect("invalid user ID");
+
+    let wallet = client.current_user_wallet().unwrap();
+
+    mock.assert();
+    assert_eq!(wallet.total_balance(), Amount::from_repr(170_25));
+}
+
+/// Tests that the endpoints are appended to the full path of the base URL.
+#[test]
+fn it_client_base_url_path() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/api/v1/user/current/wallet")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .expect(2)
+        .create();
+
+    let mut client = mock_client(&server);
+    for base_url in &["/api/v1", "/api/v1/"] {
+        client
+            .set_base_url(Url::parse(&format!("{}{}", server.url(), base_url)).unwrap())
+            .unwrap();
+        assert_eq!(
+            client.base_url().as_str(),
+            format!("{}/api/v1/", server.url())
+        );
+
+        let _ = client.current_user_wallet().unwrap();
+    }
+
+    mock.assert();
+
+    let error = client
+        .set_base_url(Url::parse("mailto:john.doe@example.com").unwrap())
+        .unwrap_err();
+    assert_eq!(error.as_api_error(), Some(&ApiError::InvalidBaseUrl));
+    assert_eq!(
+        client.base_url().as_str(),
+        format!("{}/api/v1/", server.url())
+    );
+
+    let error = Client::builder()
+        .base_url(Url::parse("mailto:john.doe@example.com").unwrap())
+        .build()
+        .unwrap_err();
+    assert_eq!(error.as_api_error(), Some(&ApiError::InvalidBaseUrl));
+}
+
+/// Gets the JSON representation of a transaction with the given ID and start date.
+fn transaction_json(id: &str, started_date: i64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "legId": "d4e5f6a7-b8c9-4d0e-9f1a-2b3c4d5e6f7a",
+        "type": "CARD_PAYMENT",
+        "state": "COMPLETED",
+        "startedDate": started_date,
+        "updatedDate": started_date,
+        "currency": "EUR",
+        "amount": -10_00,
+        "fee": 0,
+        "description": "Coffee shop",
+    })
+}
+
+/// Tests the retrieval of the details of a transaction.
+#[test]
+fn it_current_user_transaction() {
+    let mut server = Server::new();
+    let transaction_id = "d7a8fbb3-07d4-4e8f-9a2e-3c5b6d7e8f90";
+    let transfer_id = "f1e2d3c4-b5a6-4978-8695-a4b3c2d1e0f9";
+    let counterparty_id = "8c7b6a5f-4e3d-4c2b-9a1f-0e9d8c7b6a5f";
+
+    let mut payment = transaction_json(transaction_id, 1_546_300_800_000);
+    let _ = payment.as_object_mut().unwrap().insert(
+        "merchant".to_owned(),
+        json!({ "name": "Coffee shop", "city": "Paris", "country": "FR" }),
+    );
+    let _ = payment
+        .as_object_mut()
+        .unwrap()
+        .insert("category".to_owned(), json!("restaurants"));
+    let payment_mock = server
+        .mock(
+            "GET",
+            format!("/user/current/transactions/{}", transaction_id).as_str(),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(payment.to_string())
+        .create();
+
+    let mut transfer = transaction_json(transfer_id, 1_546_300_800_000);
+    let _ = transfer.as_object_mut().unwrap().insert(
+        "counterparty".to_owned(),
+        json!({ "id": counterparty_id, "type": "REVOLUT", "name": "Jane Doe" }),
+    );
+    let transfer_mock = server
+        .mock(
+            "GET",
+            format!("/user/current/transactions/{}", transfer_id).as_str(),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transfer.to_string())
+        .create();
+
+    let not_found_mock = server
+        .mock(
+            "GET",
+            format!("/user/current/transactions/{}", Uuid::nil()).as_str(),
+        )
+        .with_status(404)
+        .create();
+
+    let client = mock_client(&server);
+
+    let transaction = client
+        .current_user_transaction(transaction_id.parse().unwrap())
+        .unwrap();
+    assert_eq!(transaction.id(), transaction_id.parse().unwrap());
+    assert_eq!(transaction.category().as_ref().unwrap(), "restaurants");
+    let merchant = transaction.merchant().as_ref().unwrap();
+    assert_eq!(merchant.name(), "Coffee shop");
+    assert_eq!(merchant.city().as_ref().unwrap(), "Paris");
+    assert_eq!(merchant.country(), &Some(Country::France));
+    assert!(transaction.counterparty().is_none());
+
+    let transaction = client
+        .current_user_transaction(transfer_id.parse().unwrap())
+        .unwrap();
+    assert!(transaction.merchant().is_none());
+    assert!(transaction.category().is_none());
+    let counterparty = transaction.counterparty().as_ref().unwrap();
+    assert_eq!(counterparty.id(), Some(counterparty_id.parse().unwrap()));
+    assert_eq!(counterparty.counterparty_type(), "REVOLUT");
+    assert_eq!(counterparty.name().as_ref().unwrap(), "Jane Doe");
+
+    let response = client.current_user_transaction(Uuid::nil());
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotFound
+    );
+
+    payment_mock.assert();
+    transfer_mock.assert();
+    not_found_mock.assert();
+}
+
+/// Tests the download of the statements of the current user.
+#[test]
+fn it_current_user_statement() {
+    let statement = b"%PDF-1.4\n\x00\xff\xfe binary content".to_vec();
+    let from = Utc.ymd(2019, 1, 1).and_hms(0, 0, 0);
+    let to = Utc.ymd(2019, 2, 1).and_hms(0, 0, 0);
+
+    let mut server = Server::new();
+    let pdf_mock = server
+        .mock("GET", "/user/current/statements")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("from".to_owned(), "1546300800000".to_owned()),
+            Matcher::UrlEncoded("to".to_owned(), "1548979200000".to_owned()),
+            Matcher::UrlEncoded("format".to_owned(), "pdf".to_owned()),
+        ]))
+        .match_header("accept", "application/pdf")
+        .with_status(200)
+        .with_header("content-type", "application/pdf")
+        .with_body(&statement)
+        .create();
+    let csv_mock = server
+        .mock("GET", "/user/current/statements")
+        .match_query(Matcher::UrlEncoded("format".to_owned(), "csv".to_owned()))
+        .match_header("accept", "text/csv")
+        .with_status(401)
+        .create();
+
+    let mut client = mock_client(&server);
+    let response = client
+        .current_user_statement(from, to, StatementFormat::Pdf)
+        .unwrap();
+    assert_eq!(response, statement);
+    pdf_mock.assert();
+
+    let response = client.current_user_statement(from, to, StatementFormat::Csv);
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Unauthorized
+    );
+    csv_mock.assert();
+
+    client.unset_auth();
+    let response = client.current_user_statement(from, to, StatementFormat::Pdf);
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotLoggedIn
+    );
+}
+
+/// Tests the iteration over all the transactions, fetched in pages.
+#[test]
+fn it_transactions_iter() {
+    let mut server = Server::new();
+    let ids = [
+        "00000000-0000-4000-8000-000000000005",
+        "00000000-0000-4000-8000-000000000004",
+        "00000000-0000-4000-8000-000000000003",
+        "00000000-0000-4000-8000-000000000002",
+    ];
+
+    let first_page = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::Exact("count=3".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                transaction_json(ids[0], 500),
+                transaction_json(ids[1], 400),
+                transaction_json(ids[2], 300),
+            ])
+            .to_string(),
+        )
+        .create();
+    // The last transaction of the previous page is returned again, since it's in the boundary.
+    let second_page = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("count".to_owned(), "3".to_owned()),
+            Matcher::UrlEncoded("to".to_owned(), "300".to_owned()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([transaction_json(ids[2], 300), transaction_json(ids[3], 200),]).to_string(),
+        )
+        .create();
+
+    let client = mock_client(&server);
+    let transactions = client
+        .transactions_iter()
+        .page_size(3)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    first_page.assert();
+    second_page.assert();
+    let received_ids: Vec<_> = transactions.iter().map(|t| t.id()).collect();
+    let expected_ids: Vec<Uuid> = ids.iter().map(|id| id.parse().unwrap()).collect();
+    assert_eq!(received_ids, expected_ids);
+}
+
+/// Tests that the iteration continues after a full page of transactions started at the same date.
+#[test]
+fn it_transactions_iter_same_date_page() {
+    let mut server = Server::new();
+    let ids = [
+        "00000000-0000-4000-8000-000000000005",
+        "00000000-0000-4000-8000-000000000004",
+        "00000000-0000-4000-8000-000000000003",
+    ];
+    let same_date_page = json!([transaction_json(ids[0], 500), transaction_json(ids[1], 500)]);
+
+    let first_page = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::Exact("count=2".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(same_date_page.to_string())
+        .create();
+    // The same transactions are returned again, since all of them are in the boundary.
+    let second_page = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("count".to_owned(), "2".to_owned()),
+            Matcher::UrlEncoded("to".to_owned(), "500".to_owned()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(same_date_page.to_string())
+        .create();
+    let third_page = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("count".to_owned(), "2".to_owned()),
+            Matcher::UrlEncoded("to".to_owned(), "499".to_owned()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([transaction_json(ids[2], 400)]).to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let transactions = client
+        .transactions_iter()
+        .page_size(2)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    first_page.assert();
+    second_page.assert();
+    third_page.assert();
+    let received_ids: Vec<_> = transactions.iter().map(|t| t.id()).collect();
+    let expected_ids: Vec<Uuid> = ids.iter().map(|id| id.parse().unwrap()).collect();
+    assert_eq!(received_ids, expected_ids);
+}
+
+/// Tests the pagination metadata of the transaction pages.
+#[test]
+fn it_current_user_transactions_pages() {
+    let mut server = Server::new();
+    let ids = [
+        "00000000-0000-4000-8000-000000000005",
+        "00000000-0000-4000-8000-000000000004",
+        "00000000-0000-4000-8000-000000000003",
+    ];
+
+    let first_page_mock = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::Exact("count=2".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([transaction_json(ids[0], 500), transaction_json(ids[1], 400)]).to_string(),
+        )
+        .create();
+    let second_page_mock = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("count".to_owned(), "2".to_owned()),
+            Matcher::UrlEncoded("to".to_owned(), "400".to_owned()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([transaction_json(ids[2], 300)]).to_string())
+        .create();
+
+    let client = mock_client(&server);
+    let first_page = client.current_user_transactions(None, 2).unwrap();
+    assert_eq!(first_page.items().len(), 2);
+    assert!(first_page.has_more());
+    assert_eq!(first_page.next_cursor().as_deref(), Some("400"));
+    first_page_mock.assert();
+
+    let second_page = client
+        .current_user_transactions(first_page.next_cursor().as_deref(), 2)
+        .unwrap();
+    assert!(!second_page.has_more());
+    assert!(second_page.next_cursor().is_none());
+    let received_ids: Vec<_> = second_page.into_items().iter().map(|t| t.id()).collect();
+    assert_eq!(received_ids, vec![ids[2].parse::<Uuid>().unwrap()]);
+    second_page_mock.assert();
+
+    let response = client.current_user_transactions(Some("not-a-cursor"), 2);
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::InvalidCursor
+    );
+}
+
+/// Tests that the cursor of the transaction pages advances if a full page has the same date.
+#[test]
+fn it_current_user_transactions_same_date_page() {
+    let mut server = Server::new();
+    let ids = [
+        "00000000-0000-4000-8000-000000000005",
+        "00000000-0000-4000-8000-000000000004",
+    ];
+
+    let mock = server
+        .mock("GET", "/user/current/transactions")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("count".to_owned(), "2".to_owned()),
+            Matcher::UrlEncoded("to".to_owned(), "500".to_owned()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([transaction_json(ids[0], 500), transaction_json(ids[1], 500)]).to_string(),
+        )
+        .create();
+
+    let client = mock_client(&server);
+    let page = client.current_user_transactions(Some("500"), 2).unwrap();
+    mock.assert();
+    assert!(page.has_more());
+    assert_eq!(page.next_cursor().as_deref(), Some("499"));
+}
+
+/// Tests the retrieval of a single card.
+#[test]
+fn it_current_user_card() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+
+    let mock = server
+        .mock("GET", format!("/user/current/cards/{}", card_id).as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(card_json(card_id).to_string())
+        .create();
+
+    let card = mock_client(&server)
+        .current_user_card(card_id.parse().unwrap())
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(card.owner_id(), MOCK_USER_ID.parse().unwrap());
+    assert_eq!(card.last_four(), "1234");
+    assert_eq!(card.brand(), &CardBrand::Visa);
+    assert_eq!(card.expiry_date().year(), 2030);
+}
+
+/// Tests the retrieval of the saved addresses of the user.
+#[test]
+fn it_current_user_addresses() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/addresses")
+        .match_header("authorization", Matcher::Regex("^Basic ".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {
+                    "city": "New City",
+                    "country": "FR",
+                    "postcode": "39325",
+                    "region": "NewRegion",
+                    "streetLine1": "Street 1, 6",
+                    "streetLine2": "Apt. 5"
+                },
+                {
+                    "city": "London",
+                    "country": "GB",
+                    "postcode": "EC1A 1BB",
+                    "region": "London",
+                    "streetLine1": "1 Some Street"
+                }
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let addresses = mock_client(&server).current_user_addresses().unwrap();
+    mock.assert();
+
+    assert_eq!(addresses.len(), 2);
+    assert_eq!(
+        addresses[0],
+        Address::new(
+            "New City",
+            "FR",
+            "39325",
+            "NewRegion",
+            "Street 1, 6",
+            Some("Apt. 5".to_owned())
+        )
+    );
+    assert_eq!(addresses[1].country(), &Country::UnitedKingdom);
+    assert_eq!(addresses[1].street_line_2(), &None);
+}
+
+/// Tests that the cards can be fetched again with the IDs read from the listed cards.
+#[test]
+fn it_current_user_cards_ids() {
+    let mut server = Server::new();
+    let card_ids = [
+        "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d",
+        "b6f9c2d3-4e5a-4b7c-8d9e-0f1a2b3c4d5e",
+    ];
+
+    let list_mock = server
+        .mock("GET", "/user/current/cards")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([card_json(card_ids[0]), card_json(card_ids[1])]).to_string())
+        .create();
+    let card_mocks: Vec<_> = card_ids
+        .iter()
+        .map(|card_id| {
+            server
+                .mock("GET", format!("/user/current/cards/{}", card_id).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(card_json(card_id).to_string())
+                .create()
+        })
+        .collect();
+
+    let client = mock_client(&server);
+    let cards = client.current_user_cards().unwrap();
+    list_mock.assert();
+
+    let ids: Vec<_> = cards.iter().map(Card::id).collect();
+    let expected_ids: Vec<Uuid> = card_ids.iter().map(|id| id.parse().unwrap()).collect();
+    assert_eq!(ids, expected_ids);
+
+    for card in &cards {
+        assert_eq!(&client.current_user_card(card.id()).unwrap(), card);
+    }
+    for mock in &card_mocks {
+        mock.assert();
+    }
+}
+
+/// Tests the retrieval of a card that does not exist.
+#[test]
+fn it_current_user_card_not_found() {
+    let mut server = Server::new();
+    let card_id = "a5e8b1c2-3d4f-4a6b-9c8d-7e6f5a4b3c2d";
+
+    let mock = server
+        .mock("GET", format!("/user/current/cards/{}", card_id).as_str())
+        .with_status(404)
+        .create();
+
+    let response = mock_client(&server).current_user_card(card_id.parse().unwrap());
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotFound
+    );
+}
+
+/// Tests that a client built with an external HTTP client uses it for the requests.
+#[test]
+fn it_with_http_client() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("X-Injected", "yes")
+        .match_header("user-agent", "Revolut/com.revolut.revolut (android)")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .expect(2)
+        .create();
+
+    let mut default_headers = HeaderMap::new();
+    let _ = default_headers.insert("X-Injected", HeaderValue::from_static("yes"));
+    let http_client = reqwest::Client::builder()
+        .default_headers(default_headers)
+        .build()
+        .unwrap();
+
+    let mut client = Client::with_http_client(http_client.clone());
+    client
+        .set_base_url(Url::parse(&server.url()).unwrap())
+        .unwrap();
+    client.set_options(Options::android());
+    client
+        .set_auth(MOCK_USER_ID, MOCK_ACCESS_TOKEN)
+        .expect("invalid user ID");
+    let _ = client.current_user_wallet().unwrap();
+
+    let client = Client::builder()
+        .http_client(http_client)
+        .options(Options::android())
+        .base_url(Url::parse(&server.url()).unwrap())
+        .user_id(MOCK_USER_ID)
+        .access_token(MOCK_ACCESS_TOKEN)
+        .build()
+        .unwrap();
+    let _ = client.current_user_wallet().unwrap();
+
+    mock.assert();
+}
+
+/// Tests the login, signing in and confirming the sign in with the provided code.
+#[test]
+fn it_login() {
+    let mut server = Server::new();
+    let sign_in_mock = server
+        .mock("POST", "/signin")
+        .match_body(Matcher::Json(json!({
+            "phone": "+1555555555",
+            "password": "9999",
+        })))
+        .with_status(204)
+        .create();
+    let confirm_mock = server
+        .mock("POST", "/signin/confirm")
+        .match_body(Matcher::Json(json!({
+            "phone": "+1555555555",
+            "code": "111111",
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "user": user_json("+1555555555", "john.doe@example.com"),
+                "wallet": wallet_json(),
+                "accessToken": MOCK_ACCESS_TOKEN,
+            })
+            .to_string(),
+        )
+        .create();
+
+    let mut client = Client::default();
+    client
+        .set_base_url(Url::parse(&server.url()).unwrap())
+        .unwrap();
+
+    let (user, wallet) = client
+        .login("+1555555555", "9999", || "111-111".to_owned())
+        .unwrap()
+        .into_logged_in()
+        .unwrap();
+
+    sign_in_mock.assert();
+    confirm_mock.assert();
+    assert_eq!(user.first_name(), "John");
+    assert_eq!(wallet.total_balance(), Amount::from_repr(170_25));
+    assert_eq!(client.user_id(), Some(MOCK_USER_ID.parse().unwrap()));
+    assert_eq!(client.access_token().unwrap(), MOCK_ACCESS_TOKEN);
+}
+
+/// Tests that addresses keep their country when serialized and deserialized.
+#[test]
+fn it_address_country_round_trip() {
+    let address = Address::new("Paris", "FR", "75001", "Paris", "Rue de Rivoli, 1", None);
+    assert_eq!(address.country(), &Country::France);
+
+    let value = serde_json::to_value(&address).unwrap();
+    assert_eq!(value["country"], json!("FR"));
+    assert_eq!(serde_json::from_value::<Address>(value).unwrap(), address);
+
+    let address = Address::new(
+        "Pristina",
+        Country::from("XK"),
+        "10000",
+        "Pristina",
+        "Street 1",
+        None,
+    );
+    let value = serde_json::to_value(&address).unwrap();
+    assert_eq!(value["country"], json!("XK"));
+    let address: Address = serde_json::from_value(value).unwrap();
+    assert_eq!(address.country(), &Country::Unknown("XK".to_owned()));
+
+    let mut update = AddressUpdate::default();
+    let _ = update.set_country(Country::Spain);
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({ "country": "ES" })
+    );
+}
+
+/// Tests the creation of addresses with the builder.
+#[test]
+fn it_address_builder() {
+    let address = Address::builder()
+        .city("New City")
+        .country("FR")
+        .postcode("39325")
+        .region("NewRegion")
+        .street_line_1("Street 1, 6")
+        .street_line_2("Apt. 5")
+        .build()
+        .unwrap();
+    assert_eq!(
+        address,
+        Address::new(
+            "New City",
+            "FR",
+            "39325",
+            "NewRegion",
+            "Street 1, 6",
+            Some("Apt. 5".to_owned())
+        )
+    );
+
+    let address = Address::builder()
+        .city("New City")
+        .country(Country::France)
+        .postcode("39325")
+        .region("NewRegion")
+        .street_line_1("Street 1, 6")
+        .build()
+        .unwrap();
+    assert_eq!(
+        address,
+        Address::new("New City", "FR", "39325", "NewRegion", "Street 1, 6", None)
+    );
+
+    let response = Address::builder()
+        .city("New City")
+        .country("FR")
+        .postcode("39325")
+        .street_line_1("Street 1, 6")
+        .build();
+    assert!(response.unwrap_err().contains("region"));
+}
+
+/// Tests the partial update of the address of the current user.
+#[test]
+fn it_patch_current_user_address() {
+    let mut update = AddressUpdate::default();
+    let _ = update.set_city("New City").set_street_line_2("Apt. 7");
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        json!({
+            "city": "New City",
+            "streetLine2": "Apt. 7",
+        })
+    );
+
+    let mut server = Server::new();
+    let mock = server
+        .mock("PATCH", "/user/current")
+        .match_body(Matcher::Json(json!({
+            "address": {
+                "city": "New City",
+                "streetLine2": "Apt. 7",
+            }
+        })))
+        .with_status(204)
+        .create();
+
+    mock_client(&server)
+        .patch_current_user_address(&update)
+        .unwrap();
+
+    mock.assert();
+}
+
+/// Tests the change of the email address of the current user.
+#[test]
+fn it_change_current_user_email() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("PATCH", "/user/current")
+        .match_body(Matcher::Json(json!({
+            "email": "john.doe@example.com",
+        })))
+        .with_status(204)
+        .create();
+
+    let client = mock_client(&server);
+    client
+        .change_current_user_email("john.doe@example.com")
+        .unwrap();
+    mock.assert();
+
+    let response = client.change_current_user_email("john.doe");
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::InvalidEmail
+    );
+}
+
+/// Tests that a rejected email address returns the error sent by the API.
+#[test]
+fn it_change_current_user_email_bad_request() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("PATCH", "/user/current")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"message": "Email is already in use", "code": 2004}).to_string())
+        .create();
+
+    let response = mock_client(&server).change_current_user_email("john.doe@example.com");
+
+    mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::BadRequest {
+            message: "Email is already in use".to_owned(),
+            code: Some(2004),
+        }
+    );
+}
+
+/// Tests the listing of the devices of the current user.
+#[test]
+fn it_current_user_devices() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/devices")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {
+                    "id": "9a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d",
+                    "model": "iPhone10,6",
+                    "lastActive": 1_547_047_020_000_i64,
+                    "current": true,
+                },
+                {
+                    "id": "1f2e3d4c-5b6a-4978-8695-a4b3c2d1e0f9",
+                    "model": "SM-G960F",
+                    "lastActive": 1_546_300_800_000_i64,
+                    "current": false,
+                },
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let devices = mock_client(&server).current_user_devices().unwrap();
+    mock.assert();
+
+    assert_eq!(devices.len(), 2);
+    assert_eq!(
+        devices[0].id(),
+        "9a1b2c3d-4e5f-4a6b-8c7d-9e0f1a2b3c4d"
+            .parse::<Uuid>()
+            .unwrap()
+    );
+    assert_eq!(devices[0].model(), "iPhone10,6");
+    assert_eq!(
+        devices[0].last_active(),
+        Utc.timestamp_millis_opt(1_547_047_020_000).unwrap()
+    );
+    assert!(devices[0].current());
+    assert!(!devices[1].current());
+}
+
+/// Tests the revocation of a device of the current user.
+#[test]
+fn it_revoke_device() {
+    let mut server = Server::new();
+    let device_id = "1f2e3d4c-5b6a-4978-8695-a4b3c2d1e0f9";
+    let revoke_mock = server
+        .mock(
+            "DELETE",
+            format!("/user/current/devices/{}", device_id).as_str(),
+        )
+        .match_header("authorization", Matcher::Regex("^Basic ".to_owned()))
+        .with_status(204)
+        .create();
+    let not_found_mock = server
+        .mock(
+            "DELETE",
+            Matcher::Regex("^/user/current/devices/".to_owned()),
+        )
+        .with_status(404)
+        .create();
+
+    let mut client = mock_client(&server);
+    client.revoke_device(device_id.parse().unwrap()).unwrap();
+    revoke_mock.assert();
+
+    let response = client.revoke_device(Uuid::nil());
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotFound
+    );
+    not_found_mock.assert();
+
+    client.unset_auth();
+    let response = client.revoke_device(device_id.parse().unwrap());
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::NotLoggedIn
+    );
+}
+
+/// Tests that the passcode is only sent with the operations requiring verification.
+#[test]
+fn it_verify_password() {
+    let mut server = Server::new();
+    let device_id = "1f2e3d4c-5b6a-4978-8695-a4b3c2d1e0f9";
+    let revoke_mock = server
+        .mock(
+            "DELETE",
+            format!("/user/current/devices/{}", device_id).as_str(),
+        )
+        .match_header("x-verify-password", "1234")
+        .with_status(204)
+        .create();
+    let email_mock = server
+        .mock("PATCH", "/user/current")
+        .match_header("x-verify-password", "1234")
+        .with_status(204)
+        .create();
+    let wallet_mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("x-verify-password", Matcher::Missing)
+        .with_status(200)
+        .with_body(wallet_json().to_string())
+        .create();
+
+    let mut client = mock_client(&server);
+    for passcode in &["", "12a4", "12 34"] {
+        let response = client.set_verify_password(passcode);
+        assert_eq!(
+            response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+            &ApiError::InvalidPasscode
+        );
+    }
+    client.set_verify_password("1234").unwrap();
+
+    client.revoke_device(device_id.parse().unwrap()).unwrap();
+    client
+        .change_current_user_email("john.doe@example.com")
+        .unwrap();
+    let _ = client.current_user_wallet().unwrap();
+    revoke_mock.assert();
+    email_mock.assert();
+    wallet_mock.assert();
+    assert!(!format!("{:?}", client).contains("1234"));
+
+    client.unset_verify_password();
+    let response = client.revoke_device(device_id.parse().unwrap());
+    assert!(response.is_err());
+}
+
+/// Tests that the passcode of a shared client is removed when signing out.
+#[test]
+fn it_shared_client_sign_out_verify_password() {
+    let mut server = Server::new();
+    let device_id = "1f2e3d4c-5b6a-4978-8695-a4b3c2d1e0f9";
+    let sign_out_mock = server.mock("POST", "/signout").with_status(204).create();
+    let revoke_mock = server
+        .mock(
+            "DELETE",
+            format!("/user/current/devices/{}", device_id).as_str(),
+        )
+        .match_header("x-verify-password", Matcher::Missing)
+        .with_status(204)
+        .create();
+
+    let shared = SharedClient::new(mock_client(&server));
+    shared.write().set_verify_password("1234").unwrap();
+    shared.sign_out().unwrap();
+    sign_out_mock.assert();
+    assert!(!shared.read().is_authenticated());
+
+    shared.set_auth(MOCK_USER_ID, MOCK_ACCESS_TOKEN).unwrap();
+    shared
+        .read()
+        .revoke_device(device_id.parse().unwrap())
+        .unwrap();
+    revoke_mock.assert();
+}
+
+/// Tests the retrieval of the user, wallet and cards at once.
+#[test]
+fn it_current_user_full() {
+    let mut server = Server::new();
+    let user_mock = server
+        .mock("GET", "/user/current")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "user": user_json("+1555555555", "john.doe@example.com"),
+                "wallet": wallet_json()
+            })
+            .to_string(),
+        )
+        .create();
+    let cards_mock = server
+        .mock("GET", "/user/current/cards")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([card_json("5e3b6a2c-1d4f-4e8a-9b7c-0d1e2f3a4b5c")]).to_string())
+        .create();
+
+    let full = mock_client(&server).current_user_full().unwrap();
+    user_mock.assert();
+    cards_mock.assert();
+
+    assert_eq!(full.user().email().as_str(), "john.doe@example.com");
+    assert_eq!(full.wallet().pockets().len(), 4);
+    assert_eq!(full.cards().len(), 1);
+
+    let (user, wallet, cards) = full.into_parts();
+    assert_eq!(user.phone().as_str(), "+1555555555");
+    assert_eq!(wallet.total_balance_in("EUR"), Amount::from_repr(170_25));
+    assert_eq!(cards[0].owner_id(), MOCK_USER_ID.parse::<Uuid>().unwrap());
+}
+
+/// Tests that the first error is returned when retrieving the user, wallet and cards at once.
+#[test]
+fn it_current_user_full_partial_failure() {
+    let mut server = Server::new();
+    let user_mock = server
+        .mock("GET", "/user/current")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "user": user_json("+1555555555", "john.doe@example.com"),
+                "wallet": wallet_json()
+            })
+            .to_string(),
+        )
+        .create();
+    let cards_mock = server
+        .mock("GET", "/user/current/cards")
+        .with_status(500)
+        .with_body("internal error")
+        .create();
+
+    let response = mock_client(&server).current_user_full();
+    user_mock.assert();
+    cards_mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Other {
+            status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            body: Some("internal error".to_owned()),
+        }
+    );
+
+    let mut server = Server::new();
+    let user_mock = server
+        .mock("GET", "/user/current")
+        .with_status(401)
+        .create();
+    let cards_mock = server.mock("GET", "/user/current/cards").expect(0).create();
+
+    let response = mock_client(&server).current_user_full();
+    user_mock.assert();
+    cards_mock.assert();
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::Unauthorized
+    );
+}
+
+/// Tests the retrieval of the top-up limits of the current user.
+#[test]
+fn it_current_user_limits() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/user/current/limits")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "topupUsed": 1_500_00,
+                "topupMax": 5_000_00,
+                "resetDate": 1_548_979_200_000_i64,
+                "currencies": [
+                    { "currency": "EUR", "used": 1_500_00, "max": 5_000_00 },
+                    { "currency": "GBP", "used": 0, "max": 4_000_00 },
+                ]
+            })
+            .to_string(),
+        )
+        .create();
+
+    let limits = mock_client(&server).current_user_limits().unwrap();
+    mock.assert();
+
+    assert_eq!(limits.topup_used(), Amount::from_repr(1_500_00));
+    assert_eq!(limits.topup_max(), Amount::from_repr(5_000_00));
+    assert_eq!(limits.topup_remaining(), Amount::from_repr(3_500_00));
+    assert_eq!(
+        limits.reset_date(),
+        Utc.timestamp_millis_opt(1_548_979_200_000).unwrap()
+    );
+    assert_eq!(limits.currencies().len(), 2);
+
+    let gbp = limits.currency("GBP").unwrap();
+    assert_eq!(gbp.used(), Amount::zero());
+    assert_eq!(gbp.max(), Amount::from_repr(4_000_00));
+    assert!(limits.currency("USD").is_none());
+}
+
+/// Tests that the authentication of a shared client is visible from every thread.
+#[test]
+fn it_shared_client() {
+    let mut server = Server::new();
+    let new_token = "9a4d5a6c-3b2e-4f1d-8c7b-6a5f4e3d2c1b";
+    let refresh_mock = server
+        .mock("POST", "/signin/refresh")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "accessToken": new_token }).to_string())
+        .create();
+    let wallet_mock = server
+        .mock("GET", "/user/current/wallet")
+        .match_header("authorization", Matcher::Regex("^Basic ".to_owned()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(wallet_json().to_string())
+        .expect(8)
+        .create();
+
+    let mut client = mock_client(&server);
+    client.unset_auth();
+    let shared = SharedClient::new(client);
+    assert!(!shared.read().is_authenticated());
+
+    shared.set_auth(MOCK_USER_ID, MOCK_ACCESS_TOKEN).unwrap();
+    shared.refresh_token().unwrap();
+    refresh_mock.assert();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                let client = shared.read();
+                assert_eq!(client.access_token().unwrap(), new_token);
+                client.current_user_wallet().unwrap()
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap().pockets().len(), 4);
+    }
+    wallet_mock.assert();
+
+    let other = shared.clone();
+    other.unset_auth();
+    assert!(!shared.read().is_authenticated());
+    let response = shared.read().current_user_wallet();
+    assert_eq!(
+        response.err().unwrap().as_api_error(),
+        Some(&ApiError::NotLoggedIn)
+    );
+}
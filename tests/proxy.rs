@@ -0,0 +1,29 @@
+//! Proxy configuration tests.
+//!
+//! They are in their own test binary, since they change the environment of the process, and the
+//! tests of the other binaries run in parallel with each other.
+
+use std::env;
+
+use revolut_customer::{ApiError, Client};
+
+/// Tests that the proxy is only read from the environment on demand.
+#[test]
+fn it_proxy_from_env() {
+    let mut client = Client::default();
+
+    env::remove_var("HTTPS_PROXY");
+    env::remove_var("https_proxy");
+    assert!(!client.set_proxy_from_env().unwrap());
+
+    env::set_var("HTTPS_PROXY", "http://127.0.0.1:8080");
+    assert!(client.set_proxy_from_env().unwrap());
+
+    env::set_var("HTTPS_PROXY", "not a proxy");
+    let response = client.set_proxy_from_env();
+    env::remove_var("HTTPS_PROXY");
+    assert_eq!(
+        response.err().unwrap().downcast_ref::<ApiError>().unwrap(),
+        &ApiError::InvalidProxy
+    );
+}
@@ -1 +1,135 @@
 //! Public API methods tests.
+
+use mockito::{Matcher, Server};
+use reqwest::{StatusCode, Url};
+use revolut_customer::{
+    public::{CountryInfo, CurrencyInfo},
+    ApiError, Client, Country,
+};
+use serde_json::json;
+
+/// Creates a client without authentication information pointing to the given mock server.
+fn mock_client(server: &Server) -> Client {
+    let mut client = Client::default();
+    client
+        .set_base_url(Url::parse(&server.url()).expect("invalid mock server URL"))
+        .unwrap();
+    client
+}
+
+/// Tests the retrieval of the supported currencies.
+#[test]
+fn it_reference_currencies() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/reference/currencies")
+        .match_header("authorization", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"code": "EUR", "name": "Euro", "symbol": "€", "crypto": false},
+                {"code": "BTC", "name": "Bitcoin", "crypto": true}
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let currencies = mock_client(&server).reference_currencies().unwrap();
+    mock.assert();
+
+    assert_eq!(currencies.len(), 2);
+    assert_eq!(currencies[0].code(), "EUR");
+    assert_eq!(currencies[0].name(), "Euro");
+    assert_eq!(
+        currencies[0].symbol().as_ref().map(String::as_str),
+        Some("€")
+    );
+    assert!(!currencies[0].crypto());
+    assert_eq!(currencies[1].code(), "BTC");
+    assert_eq!(currencies[1].symbol(), &None);
+    assert!(currencies[1].crypto());
+}
+
+/// Tests the retrieval of the supported countries.
+#[test]
+fn it_reference_countries() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/reference/countries")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"code": "GB", "name": "United Kingdom", "supported": true},
+                {"code": "KP", "name": "North Korea", "supported": false}
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let countries = mock_client(&server).reference_countries().unwrap();
+    mock.assert();
+
+    assert_eq!(countries.len(), 2);
+    assert_eq!(countries[0].code(), &Country::UnitedKingdom);
+    assert_eq!(countries[0].name(), "United Kingdom");
+    assert!(countries[0].supported());
+    assert!(!countries[1].supported());
+}
+
+/// Tests the errors of the reference data endpoints.
+#[test]
+fn it_reference_errors() {
+    let mut server = Server::new();
+    let _mock = server
+        .mock("GET", "/reference/currencies")
+        .with_status(500)
+        .with_body("internal error")
+        .create();
+
+    let error = mock_client(&server).reference_currencies().unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ApiError>(),
+        Some(&ApiError::Other {
+            status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            body: Some("internal error".to_owned()),
+        })
+    );
+}
+
+/// Tests that the public endpoints, and the sign-in, are called without authentication.
+#[test]
+fn it_public_unauthenticated() {
+    let mut server = Server::new();
+    let currencies_mock = server
+        .mock("GET", "/reference/currencies")
+        .match_header("authorization", Matcher::Missing)
+        .with_status(200)
+        .with_body("[]")
+        .create();
+    let countries_mock = server
+        .mock("GET", "/reference/countries")
+        .match_header("authorization", Matcher::Missing)
+        .with_status(200)
+        .with_body(json!([{"code": "ES", "name": "Spain", "supported": true}]).to_string())
+        .create();
+    let sign_in_mock = server
+        .mock("POST", "/signin")
+        .match_header("authorization", Matcher::Missing)
+        .with_status(200)
+        .create();
+
+    let client = mock_client(&server);
+    assert!(!client.is_authenticated());
+
+    let currencies: Vec<CurrencyInfo> = client.reference_currencies().unwrap();
+    assert!(currencies.is_empty());
+    let countries: Vec<CountryInfo> = client.reference_countries().unwrap();
+    assert_eq!(countries[0].code(), &Country::Spain);
+    client.sign_in("+1555555555", "9999").unwrap();
+
+    currencies_mock.assert();
+    countries_mock.assert();
+    sign_in_mock.assert();
+}
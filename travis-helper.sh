@@ -7,6 +7,14 @@ if [ "$action" = "test" ]; then
     echo "cargo test --verbose" &&
     cargo test --verbose
 
+# Resolve the dependencies to the newest versions supporting the minimum Rust version. Cargo
+# only learned to do this in 1.84, so the lock file is generated with the stable toolchain.
+elif [ "$action" = "msrv_lockfile" ]; then
+  if [[ "$TRAVIS_RUST_VERSION" == "$(sed -n 's/^rust-version = "\(.*\)"/\1.0/p' Cargo.toml)" ]]; then
+    rustup toolchain install stable --profile minimal &&
+    CARGO_RESOLVER_INCOMPATIBLE_RUST_VERSIONS=fallback cargo +stable generate-lockfile
+  fi
+
 # Check formatting.
 elif [ "$action" = "fmt_check" ]; then
   if [[ "$TRAVIS_RUST_VERSION" == "stable" && "$TRAVIS_OS_NAME" == "linux" ]]; then